@@ -0,0 +1,457 @@
+//! [`Arbitrary`] implementations for generating METARs.
+//!
+//! Only combinations which display and then parse back to the same value are
+//! generated, so these are suitable for round-trip property testing. Fields
+//! which are not yet displayed (runway conditions and sea conditions) are
+//! always left empty.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    CloudDensity, CloudLayer, CloudType, Clouds, ColourCode, CompassDirection, Data, Kind, Metar,
+    Pressure, RunwayVisualRange, RvrTrend, RvrUnit, RvrValue, RvrValueInner, Time, Trend,
+    TrendNewCondition, TrendTime, VerticalVisibility, Visibility, Weather, WeatherCondition,
+    WeatherIntensity, Wind, WindDirection, WindSpeed, WindUnit, WindshearWarnings,
+};
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ALPHANUMERICS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Generate known data most of the time, and unknown data otherwise.
+fn data<'a, T>(
+    u: &mut Unstructured<'a>,
+    f: impl FnOnce(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Data<T>> {
+    if u.ratio(1, 8)? {
+        Ok(Data::Unknown)
+    } else {
+        Ok(Data::Known(f(u)?))
+    }
+}
+
+/// Generate a list of between `min` and `max` items.
+fn list<'a, T>(
+    u: &mut Unstructured<'a>,
+    min: usize,
+    max: usize,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len).map(|_| f(u)).collect()
+}
+
+/// Generate a string of `len` characters from `chars`.
+fn string(u: &mut Unstructured<'_>, chars: &[u8], len: usize) -> Result<String> {
+    (0..len)
+        .map(|_| u.choose(chars).map(|c| char::from(*c)))
+        .collect()
+}
+
+/// Generate a whole temperature in degrees Celsius.
+fn temperature(u: &mut Unstructured<'_>) -> Result<f32> {
+    Ok(f32::from(u.int_in_range(-99i8..=99)?))
+}
+
+/// Generate a time of day in `HHMM` form.
+fn time_of_day(u: &mut Unstructured<'_>) -> Result<u16> {
+    Ok(u.int_in_range(0u16..=23)? * 100 + u.int_in_range(0u16..=59)?)
+}
+
+/// Generate a runway number, e.g. `24L`.
+fn runway(u: &mut Unstructured<'_>) -> Result<String> {
+    let number = u.int_in_range(1..=36)?;
+    let suffix = u.choose(&["", "L", "C", "R"])?;
+    Ok(format!("{number:02}{suffix}"))
+}
+
+/// Generate a wind heading.
+fn heading(u: &mut Unstructured<'_>) -> Result<Data<u32>> {
+    data(u, |u| u.int_in_range(0..=360))
+}
+
+impl<'a> Arbitrary<'a> for Metar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut station = string(u, LETTERS, 4)?;
+        if station == "AUTO" {
+            station = "EGLL".to_string();
+        }
+
+        let visibility = data(u, Visibility::arbitrary)?;
+        let (weather, vert_visibility, clouds, cloud_layers) =
+            if visibility == Data::Known(Visibility::CAVOK) {
+                (Data::Known(vec![]), None, Clouds::CloudLayers, vec![])
+            } else {
+                let clouds = Clouds::arbitrary(u)?;
+                (
+                    data(u, |u| list(u, 0, 3, Weather::arbitrary))?,
+                    Option::<VerticalVisibility>::arbitrary(u)?,
+                    clouds,
+                    if clouds == Clouds::CloudLayers {
+                        list(u, 0, 4, CloudLayer::arbitrary)?
+                    } else {
+                        vec![]
+                    },
+                )
+            };
+
+        let mut trends = list(u, 0, 2, Trend::arbitrary)?;
+        // A trailing NSW would be read as part of the preceding trend
+        let mut index = 0;
+        trends.retain(|trend| {
+            index += 1;
+            index == 1 || *trend != Trend::NoSignificantWeather
+        });
+
+        Ok(Metar {
+            station,
+            time: Time::arbitrary(u)?,
+            kind: Kind::arbitrary(u)?,
+            nil: false,
+            wind: Wind::arbitrary(u)?,
+            visibility,
+            no_directional_variation: u.arbitrary()?,
+            reduced_directional_visibility: vec![],
+            rvr: list(u, 0, 2, RunwayVisualRange::arbitrary)?,
+            clouds,
+            cloud_layers,
+            vert_visibility,
+            weather,
+            temperature: data(u, temperature)?,
+            dewpoint: data(u, temperature)?,
+            pressure: Pressure::arbitrary(u)?,
+            additional_pressure: Option::<Pressure>::arbitrary(u)?,
+            colour_code: if u.arbitrary()? {
+                Some(data(u, ColourCode::arbitrary)?)
+            } else {
+                None
+            },
+            recent_weather: list(u, 0, 2, |u| {
+                data(u, |u| list(u, 1, 2, WeatherCondition::arbitrary))
+            })?,
+            windshear_warnings: Option::<WindshearWarnings>::arbitrary(u)?,
+            sea_condition: None,
+            runway_conditions: vec![],
+            trends,
+            clouds_in_vicinity: list(u, 0, 2, |u| {
+                Ok((
+                    list(u, 1, 3, CompassDirection::arbitrary)?,
+                    // Unknown (`///`) would run into a preceding unknown colour code
+                    Data::Known(*u.choose(&[CloudType::Cumulonimbus, CloudType::ToweringCumulus])?),
+                ))
+            })?,
+            remarks: if u.arbitrary()? {
+                let words = list(u, 1, 4, |u| {
+                    let len = u.int_in_range(1..=6)?;
+                    string(u, ALPHANUMERICS, len)
+                })?;
+                Some(words.join(" "))
+            } else {
+                None
+            },
+            maintenance_indicator: u.arbitrary()?,
+            raw: None,
+            warnings: vec![],
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Time {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Time {
+            date: u.int_in_range(1..=31)?,
+            hour: u.int_in_range(0..=23)?,
+            minute: u.int_in_range(0..=59)?,
+            second: if u.ratio(1, 8)? {
+                Some(u.int_in_range(0..=59)?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Kind {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            Kind::Normal,
+            Kind::Automatic,
+            Kind::Correction,
+            Kind::Amended,
+        ])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Wind {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1, 10)? {
+            return Ok(Wind::Calm);
+        }
+
+        Ok(Wind::Present {
+            dir: if u.ratio(1, 5)? {
+                WindDirection::Variable
+            } else {
+                WindDirection::Heading(heading(u)?)
+            },
+            speed: WindSpeed::arbitrary(u)?,
+            varying: if u.arbitrary()? {
+                Some((heading(u)?, heading(u)?))
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for WindSpeed {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let max = match u.int_in_range(0..=4)? {
+            0 => return Ok(WindSpeed::Greater),
+            1 => {
+                // The maximum speeds are displayed as `WindSpeed::Greater`
+                let unit = *u.choose(&[
+                    WindUnit::Knots,
+                    WindUnit::MetresPerSecond,
+                    WindUnit::KilometresPerHour,
+                ])?;
+                let speed = if unit == WindUnit::KilometresPerHour {
+                    u.int_in_range(0..=198)?
+                } else {
+                    u.int_in_range(0..=98)?
+                };
+                return Ok(WindSpeed::AtLeast { speed, unit });
+            }
+            2 | 3 => 99,
+            _ => 999,
+        };
+        let speed = data(u, |u| u.int_in_range(0..=max))?;
+        let gusting = if u.arbitrary()? {
+            Some(data(u, |u| u.int_in_range(0..=max))?)
+        } else {
+            None
+        };
+
+        Ok(match (max, u.arbitrary()?) {
+            (99, true) => WindSpeed::Knots { speed, gusting },
+            (99, false) => WindSpeed::MetresPerSecond { speed, gusting },
+            _ => WindSpeed::KilometresPerHour { speed, gusting },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Visibility {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=4)? {
+            0 => Visibility::CAVOK,
+            1 => Visibility::Metres(u.int_in_range(0..=9999)?),
+            2 => Visibility::StatuteMiles(f32::from(u.int_in_range(1u8..=99)?)),
+            3 => Visibility::MoreThanStatuteMiles(6.),
+            _ => Visibility::StatuteMiles(
+                f32::from(u.int_in_range(0u8..=2)?) + f32::from(u.int_in_range(1u8..=3)?) / 4.,
+            ),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RunwayVisualRange {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let inner = |u: &mut Unstructured<'a>| -> Result<RvrValueInner> {
+            let value = u.int_in_range(0..=9999)?;
+            Ok(match u.int_in_range(0..=2)? {
+                0 => RvrValueInner::Exactly(value),
+                1 => RvrValueInner::GreaterThan(value),
+                _ => RvrValueInner::LessThan(value),
+            })
+        };
+
+        Ok(RunwayVisualRange {
+            runway: runway(u)?,
+            value: data(u, |u| {
+                Ok(if u.arbitrary()? {
+                    RvrValue::Single(inner(u)?)
+                } else {
+                    RvrValue::Between(inner(u)?, inner(u)?)
+                })
+            })?,
+            unit: *u.choose(&[RvrUnit::Metres, RvrUnit::Feet])?,
+            trend: Data::Known(*u.choose(&[
+                RvrTrend::Upwards,
+                RvrTrend::Downwards,
+                RvrTrend::None,
+            ])?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for WindshearWarnings {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.ratio(1, 4)? {
+            WindshearWarnings::All
+        } else {
+            WindshearWarnings::Runways(list(u, 1, 2, runway)?)
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Weather {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Recent weather is generated separately, as it is moved out of the
+        // current weather when parsed
+        Ok(Weather {
+            intensity: *u.choose(&[
+                WeatherIntensity::Light,
+                WeatherIntensity::Moderate,
+                WeatherIntensity::Heavy,
+                WeatherIntensity::InVicinity,
+                WeatherIntensity::Unknown,
+            ])?,
+            conditions: list(u, 1, 3, WeatherCondition::arbitrary)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for WeatherCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&WeatherCondition::ALL)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for VerticalVisibility {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.ratio(1, 4)? {
+            VerticalVisibility::ReducedByUnknownAmount
+        } else {
+            VerticalVisibility::Distance(u.int_in_range(0..=999)?)
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Clouds {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            Clouds::NoCloudDetected,
+            Clouds::SkyClear,
+            Clouds::Clear,
+            Clouds::NoSignificantCloud,
+            Clouds::CloudLayers,
+            Clouds::CloudLayers,
+        ])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for CloudLayer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(CloudLayer {
+            density: data(u, |u| {
+                Ok(*u.choose(&[
+                    CloudDensity::Few,
+                    CloudDensity::Scattered,
+                    CloudDensity::Broken,
+                    CloudDensity::Overcast,
+                ])?)
+            })?,
+            kind: data(u, |u| {
+                Ok(*u.choose(&[
+                    CloudType::Normal,
+                    CloudType::Cumulonimbus,
+                    CloudType::ToweringCumulus,
+                ])?)
+            })?,
+            height: data(u, |u| u.int_in_range(0..=999))?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompassDirection {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            CompassDirection::North,
+            CompassDirection::NorthEast,
+            CompassDirection::East,
+            CompassDirection::SouthEast,
+            CompassDirection::South,
+            CompassDirection::SouthWest,
+            CompassDirection::West,
+            CompassDirection::NorthWest,
+        ])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Pressure {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Pressure::Hectopascals(data(u, |u| u.int_in_range(0..=9999))?)
+        } else {
+            Pressure::InchesOfMercury(data(u, |u| {
+                Ok(f32::from(u.int_in_range(0u16..=9999)?) / 100.)
+            })?)
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ColourCode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(u.choose(&[
+            ColourCode::BluePlus,
+            ColourCode::Blue,
+            ColourCode::White,
+            ColourCode::Green,
+            ColourCode::Yellow,
+            ColourCode::Amber,
+            ColourCode::Red,
+        ])?
+        .clone())
+    }
+}
+
+impl<'a> Arbitrary<'a> for Trend {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => Trend::NoSignificantChanges,
+            1 => Trend::NoSignificantWeather,
+            2 => Trend::Becoming(TrendNewCondition::arbitrary(u)?),
+            _ => Trend::Temporarily(TrendNewCondition::arbitrary(u)?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TrendNewCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut cond = TrendNewCondition {
+            time: list(u, 0, 2, TrendTime::arbitrary)?,
+            wind: Option::<Wind>::arbitrary(u)?,
+            visibility: Option::<Visibility>::arbitrary(u)?,
+            weather: list(u, 0, 2, Weather::arbitrary)?,
+            cloud: list(u, 0, 2, CloudLayer::arbitrary)?,
+            vertical_visibility: Option::<VerticalVisibility>::arbitrary(u)?,
+            colour_code: if u.arbitrary()? {
+                Some(data(u, ColourCode::arbitrary)?)
+            } else {
+                None
+            },
+        };
+
+        // A trend must change something
+        if cond.wind.is_none()
+            && cond.visibility.is_none()
+            && cond.weather.is_empty()
+            && cond.cloud.is_empty()
+            && cond.vertical_visibility.is_none()
+            && cond.colour_code.is_none()
+        {
+            cond.visibility = Some(Visibility::CAVOK);
+        }
+        Ok(cond)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TrendTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let time = time_of_day(u)?;
+        Ok(match u.int_in_range(0..=2)? {
+            0 => TrendTime::From(time),
+            1 => TrendTime::Until(time),
+            _ => TrendTime::At(time),
+        })
+    }
+}
@@ -146,7 +146,73 @@ impl fmt::Display for OwnedMetarError {
     }
 }
 
+impl std::error::Error for OwnedMetarError {}
+
+/// All of the errors from parsing a METAR, owning the input so it can be
+/// passed around freely, e.g. with `?`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MetarParseError {
+    /// The errors that occurred
+    pub errors: Vec<OwnedMetarError>,
+}
+
+impl fmt::Display for MetarParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Each error ends in a newline, so this leaves a blank line between
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            error.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MetarParseError {}
+
+impl From<Vec<MetarError<'_>>> for MetarParseError {
+    fn from(errors: Vec<MetarError<'_>>) -> Self {
+        MetarParseError {
+            errors: errors.iter().map(MetarError::into_owned).collect(),
+        }
+    }
+}
+
+/// The 1-based line and column of the byte offset `start` in `string`,
+/// counting columns in characters. This never slices `string`, so is safe
+/// even if `start` is not on a character boundary.
+fn line_col(string: &str, start: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for (_, c) in string.char_indices().take_while(|(i, _)| *i < start) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+impl OwnedMetarError {
+    /// The 1-based line and column at which this error starts in the input,
+    /// e.g. to point at an error in a file of many METARs.
+    #[must_use]
+    pub fn line_col(&self) -> (usize, usize) {
+        line_col(&self.string, self.start)
+    }
+}
+
 impl MetarError<'_> {
+    /// The 1-based line and column at which this error starts in the input,
+    /// e.g. to point at an error in a file of many METARs.
+    #[must_use]
+    pub fn line_col(&self) -> (usize, usize) {
+        line_col(self.string, self.start)
+    }
+
     /// Convert this error into an [`OwnedMetarError`]
     #[must_use]
     pub fn into_owned(&self) -> OwnedMetarError {
@@ -196,16 +262,30 @@ pub enum ErrorVariant {
     InvalidHour,
     #[display("invalid observation minute")]
     InvalidMinute,
+    #[display("invalid observation second")]
+    InvalidSecond,
 
     // WIND //
     #[display("invalid wind heading")]
     InvalidWindHeading,
+    #[display("wind variation given without a wind")]
+    WindVaryingWithoutWind,
+    #[display("wind gust given without a \"G\" separator")]
+    MissingGustSeparator,
 
     // RVR //
     #[display("invalid runway number in RVR")]
     InvalidRvrRunwayNumber,
     #[display("invalid distance in RVR")]
     InvalidRvrDistance,
+
+    // WEATHER //
+    #[display("invalid weather intensity")]
+    InvalidWeatherIntensity,
+
+    // RUNWAY CONDITION //
+    #[display("invalid runway friction")]
+    InvalidRunwayFriction,
 }
 
 impl ErrorVariant {
@@ -240,17 +320,36 @@ impl ErrorVariant {
             Self::InvalidMinute => {
                 Cow::Borrowed("the observation date must be a two digit number less than 60")
             }
+            Self::InvalidSecond => {
+                Cow::Borrowed("the observation second must be a two digit number less than 60")
+            }
 
             // WIND //
             Self::InvalidWindHeading => {
                 Cow::Borrowed("the wind heading must be three digits between 000 and 360 inclusive")
             }
+            Self::WindVaryingWithoutWind => Cow::Borrowed(
+                "a wind variation must follow the wind direction and speed, e.g. 21010KT 180V240",
+            ),
+            Self::MissingGustSeparator => {
+                Cow::Borrowed("the gust speed must be preceded by \"G\", e.g. 25015G28KT")
+            }
 
             // RVR //
             Self::InvalidRvrRunwayNumber => Cow::Borrowed(
                 r#"the runway number must be between 00 and 36, and may be suffixed with "L", "C" or "R""#,
             ),
             Self::InvalidRvrDistance => Cow::Borrowed("the RVR distance must be a 4 digit number"),
+
+            // WEATHER //
+            Self::InvalidWeatherIntensity => Cow::Borrowed(
+                "light (-) or heavy (+) intensity may only be given for precipitation, duststorms, sandstorms or funnel clouds",
+            ),
+
+            // RUNWAY CONDITION //
+            Self::InvalidRunwayFriction => Cow::Borrowed(
+                "the runway friction must be a coefficient from 00 to 90, a braking action from 91 to 95, or 99",
+            ),
         }
     }
 }
@@ -28,10 +28,17 @@
 //! correctly, please open an issue and include the METAR. This will aid in debugging
 //! the issue significantly.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
 mod error;
-pub use error::{ErrorVariant, MetarError, OwnedMetarError};
+pub use error::{ErrorVariant, MetarError, MetarParseError, OwnedMetarError};
 
 mod parsers;
+
+#[cfg(feature = "serde")]
+pub mod serde_raw;
+
 mod traits;
 
 mod types;
@@ -39,7 +39,8 @@ pub(crate) fn some_whitespace<'src>()
     text::inline_whitespace().at_least(1).or(end())
 }
 
-/// Match and parse some whitespace, demanding at least one character of whitespace
+/// Parse a two digit temperature, which is negative when prefixed with `M`.
+/// An explicit `+` is also tolerated for positive temperatures.
 pub(crate) fn temperature<'src>()
 -> impl Parser<'src, &'src str, f32, extra::Err<crate::MetarError<'src>>> {
     choice((
@@ -51,9 +52,11 @@ pub(crate) fn temperature<'src>()
                     .map(|d: &str| d.parse::<f32>().unwrap()),
             )
             .map(|(_, v)| -v),
-        text::digits(10)
-            .exactly(2)
-            .to_slice()
-            .map(|d: &str| d.parse().unwrap()),
+        just("+").or_not().ignore_then(
+            text::digits(10)
+                .exactly(2)
+                .to_slice()
+                .map(|d: &str| d.parse().unwrap()),
+        ),
     ))
 }
@@ -0,0 +1,54 @@
+//! (De)serialise a [`Metar`] as its raw report string.
+//!
+//! Use with `#[serde(with = "metar::serde_raw")]` on a [`Metar`] field to
+//! embed METARs as plain strings in configuration or data files:
+//!
+//! ```rust
+//! use metar::Metar;
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Station {
+//!     #[serde(with = "metar::serde_raw")]
+//!     latest: Metar,
+//! }
+//!
+//! let json = r#"{ "latest": "EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006" }"#;
+//! let station: Station = serde_json::from_str(json).unwrap();
+//! assert_eq!(station.latest.station, "EGHI");
+//! ```
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error as _};
+
+use crate::Metar;
+
+/// Serialise a [`Metar`] as a string, preferring the original report text
+/// where it is available.
+///
+/// # Errors
+///
+/// Returns an error if the serialiser fails.
+pub fn serialize<S: Serializer>(metar: &Metar, serializer: S) -> Result<S::Ok, S::Error> {
+    match &metar.raw {
+        Some(raw) => serializer.serialize_str(raw),
+        None => serializer.collect_str(metar),
+    }
+}
+
+/// Deserialise a [`Metar`] by parsing a string.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a string, or if the METAR fails to
+/// parse.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Metar, D::Error> {
+    let raw = String::deserialize(deserializer)?;
+    Metar::parse(&raw).map_err(|errors| {
+        D::Error::custom(
+            errors
+                .iter()
+                .map(|e| e.variant.to_string())
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    })
+}
@@ -1,5 +1,11 @@
 mod metar;
-pub use metar::Metar;
+pub use metar::{Metar, ParseOptions};
+
+mod ceiling_category;
+pub use ceiling_category::CeilingCategory;
+
+mod cloud_cover;
+pub use cloud_cover::CloudCover;
 
 mod cloud_layer;
 pub use cloud_layer::{CloudDensity, CloudLayer};
@@ -16,14 +22,26 @@ pub use colour_code::ColourCode;
 mod data;
 pub use data::Data;
 
+mod flight_category;
+pub use flight_category::FlightCategory;
+
+mod format_options;
+pub use format_options::{FormatOptions, PressureUnit, VisibilityUnit};
+
 mod kind;
 pub use kind::Kind;
 
 mod pressure;
 pub use pressure::Pressure;
 
+mod remarks;
+pub use remarks::{
+    CloudGenus, DistantPhenomenon, PeakWind, PreciseTemperature, PressureChange, PressureTendency,
+    RemarkFlag, Remarks, TornadicActivity, TornadicActivityKind, WeatherEvent,
+};
+
 mod runway_condition;
-pub use runway_condition::{RunwayCondition, RunwayContamination, RunwayDeposits};
+pub use runway_condition::{RunwayCondition, RunwayContamination, RunwayDeposits, RunwayFriction};
 
 mod rvr;
 pub use rvr::{RunwayVisualRange, RvrTrend, RvrUnit, RvrValue, RvrValueInner};
@@ -59,7 +77,7 @@ mod wind_direction;
 pub use wind_direction::WindDirection;
 
 mod wind_speed;
-pub use wind_speed::WindSpeed;
+pub use wind_speed::{WindSpeed, WindUnit};
 
 mod windshear_warnings;
-pub use windshear_warnings::{WindshearGroup, WindshearWarnings};
+pub use windshear_warnings::WindshearWarnings;
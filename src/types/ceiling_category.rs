@@ -0,0 +1,31 @@
+/// A broad band the height of the ceiling falls into, as used in ATIS and
+/// report summaries
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CeilingCategory {
+    /// Ceiling below 500ft
+    VeryLow,
+    /// Ceiling from 500ft, and below 1000ft
+    Low,
+    /// Ceiling from 1000ft up to 5000ft
+    Medium,
+    /// Ceiling above 5000ft
+    High,
+    /// No ceiling
+    Clear,
+}
+
+impl CeilingCategory {
+    /// The category of a ceiling in feet, or [`CeilingCategory::Clear`] if
+    /// there is no ceiling.
+    #[must_use]
+    pub fn from_feet(ceiling: Option<u32>) -> Self {
+        match ceiling {
+            None => CeilingCategory::Clear,
+            Some(0..500) => CeilingCategory::VeryLow,
+            Some(500..1000) => CeilingCategory::Low,
+            Some(1000..=5000) => CeilingCategory::Medium,
+            Some(_) => CeilingCategory::High,
+        }
+    }
+}
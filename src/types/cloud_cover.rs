@@ -0,0 +1,56 @@
+use std::fmt::{Display, Formatter};
+
+use super::CloudDensity;
+
+/// The amount of sky covered by a layer of cloud, ordered from least to most
+/// cover
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudCover {
+    /// Few clouds, 1 to 2 oktas
+    Few,
+    /// Scattered cloud, 3 to 4 oktas
+    Scattered,
+    /// Broken cloud, 5 to 7 oktas
+    Broken,
+    /// Overcast, 8 oktas
+    Overcast,
+}
+
+impl From<CloudDensity> for CloudCover {
+    fn from(density: CloudDensity) -> Self {
+        match density {
+            CloudDensity::Few => CloudCover::Few,
+            CloudDensity::Scattered => CloudCover::Scattered,
+            CloudDensity::Broken => CloudCover::Broken,
+            CloudDensity::Overcast => CloudCover::Overcast,
+        }
+    }
+}
+
+impl Display for CloudCover {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CloudCover::Few => "FEW",
+            CloudCover::Scattered => "SCT",
+            CloudCover::Broken => "BKN",
+            CloudCover::Overcast => "OVC",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_density() {
+        assert_eq!(CloudCover::from(CloudDensity::Few), CloudCover::Few);
+        assert_eq!(
+            CloudCover::from(CloudDensity::Overcast),
+            CloudCover::Overcast
+        );
+        assert!(CloudCover::Broken > CloudCover::Scattered);
+        assert_eq!(CloudCover::Broken.to_string(), "BKN");
+    }
+}
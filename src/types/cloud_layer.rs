@@ -14,10 +14,39 @@ pub struct CloudLayer {
     pub density: Data<CloudDensity>,
     /// Cloud type
     pub kind: Data<CloudType>,
-    /// Cloud height in feet
+    /// Cloud height in hundreds of feet, as given in the report
     pub height: Data<u32>,
 }
 
+impl CloudLayer {
+    /// The cloud height in feet
+    #[must_use]
+    pub fn height_feet(&self) -> Data<u32> {
+        self.height.map(|h| h * 100)
+    }
+
+    /// The cloud height in metres
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "cloud heights are well within the range f32 represents exactly"
+    )]
+    pub fn height_metres(&self) -> Data<f32> {
+        self.height_feet().map(|ft| ft as f32 * 0.3048)
+    }
+
+    /// The range of sky cover of this layer in oktas (eighths of the sky),
+    /// inclusive. A layer of unknown density could cover anywhere from 1 to 8
+    /// oktas.
+    #[must_use]
+    pub fn oktas(&self) -> (u8, u8) {
+        match self.density {
+            Data::Known(density) => density.oktas(),
+            Data::Unknown => (1, 8),
+        }
+    }
+}
+
 impl Parsable for CloudLayer {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         group((
@@ -61,6 +90,20 @@ pub enum CloudDensity {
     Overcast,
 }
 
+impl CloudDensity {
+    /// The range of sky cover this density represents in oktas (eighths of
+    /// the sky), inclusive
+    #[must_use]
+    pub fn oktas(&self) -> (u8, u8) {
+        match self {
+            CloudDensity::Few => (1, 2),
+            CloudDensity::Scattered => (3, 4),
+            CloudDensity::Broken => (5, 7),
+            CloudDensity::Overcast => (8, 8),
+        }
+    }
+}
+
 impl Parsable for CloudDensity {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -106,4 +149,63 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_unknown_density() {
+        let layer = CloudLayer::parse("///015").unwrap();
+        assert_eq!(
+            layer,
+            CloudLayer {
+                density: Data::Unknown,
+                height: Data::Known(15),
+                kind: Data::Known(CloudType::Normal),
+            }
+        );
+        assert_eq!(layer.height_feet(), Data::Known(1500));
+        assert_eq!(layer.to_string(), "///015");
+
+        let layer = CloudLayer::parse("///015CB").unwrap();
+        assert_eq!(layer.kind, Data::Known(CloudType::Cumulonimbus));
+        assert_eq!(layer.to_string(), "///015CB");
+    }
+
+    #[test]
+    fn test_surface_cloud_layers() {
+        for (layer_str, density) in [
+            ("OVC000", CloudDensity::Overcast),
+            ("BKN000", CloudDensity::Broken),
+        ] {
+            let layer = CloudLayer::parse(layer_str).unwrap();
+            assert_eq!(
+                layer,
+                CloudLayer {
+                    density: Data::Known(density),
+                    height: Data::Known(0),
+                    kind: Data::Known(CloudType::Normal),
+                }
+            );
+            assert_eq!(layer.to_string(), layer_str);
+        }
+    }
+
+    #[test]
+    fn test_oktas() {
+        let oktas = |layer| CloudLayer::parse(layer).unwrap().oktas();
+        assert_eq!(oktas("FEW020"), (1, 2));
+        assert_eq!(oktas("SCT020"), (3, 4));
+        assert_eq!(oktas("BKN020"), (5, 7));
+        assert_eq!(oktas("OVC020"), (8, 8));
+        assert_eq!(oktas("///020"), (1, 8));
+    }
+
+    #[test]
+    fn test_cloud_layer_height() {
+        let layer = CloudLayer::parse("BKN040").unwrap();
+        assert_eq!(layer.height_feet(), Data::Known(4000));
+        assert!((layer.height_metres().unwrap() - 1219.2).abs() < 0.01);
+        assert_eq!(
+            CloudLayer::parse("/////////").unwrap().height_metres(),
+            Data::Unknown
+        );
+    }
 }
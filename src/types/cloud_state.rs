@@ -8,8 +8,13 @@ use crate::{parsers::some_whitespace, traits::Parsable};
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Cloud state
 pub enum Clouds {
-    /// No cloud was detected, also set for CAVOK
+    /// No cloud was detected by an automatic station (`NCD`), also set for
+    /// CAVOK
     NoCloudDetected,
+    /// The sky is clear, as observed by a human (`SKC`)
+    SkyClear,
+    /// No cloud was detected below 12000ft by an automatic station (`CLR`)
+    Clear,
     /// No significant cloud was detected below 5000ft
     NoSignificantCloud,
     /// Layers of cloud, described elsewhere
@@ -20,6 +25,8 @@ impl Display for Clouds {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Clouds::NoCloudDetected => f.write_str("NCD"),
+            Clouds::SkyClear => f.write_str("SKC"),
+            Clouds::Clear => f.write_str("CLR"),
             Clouds::NoSignificantCloud => f.write_str("NSC"),
             Clouds::CloudLayers => Ok(()),
         }
@@ -35,8 +42,11 @@ impl Parsable for Clouds {
             just("NSC")
                 .map(|_| Clouds::NoSignificantCloud)
                 .then_ignore(some_whitespace()),
+            just("SKC")
+                .map(|_| Clouds::SkyClear)
+                .then_ignore(some_whitespace()),
             just("CLR")
-                .map(|_| Clouds::NoCloudDetected)
+                .map(|_| Clouds::Clear)
                 .then_ignore(some_whitespace()),
             empty().map(|()| Clouds::CloudLayers),
         ))
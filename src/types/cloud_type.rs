@@ -16,6 +16,19 @@ pub enum CloudType {
     ToweringCumulus,
 }
 
+impl CloudType {
+    /// How hazardous this type of cloud is, for sorting layers: normal
+    /// cloud is 0, towering cumulus 1 and cumulonimbus 2.
+    #[must_use]
+    pub fn severity(&self) -> u8 {
+        match self {
+            CloudType::Normal => 0,
+            CloudType::ToweringCumulus => 1,
+            CloudType::Cumulonimbus => 2,
+        }
+    }
+}
+
 impl Parsable for CloudType {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
@@ -28,15 +41,21 @@ impl Parsable for CloudType {
 
 impl Parsable for (Vec<CompassDirection>, Data<CloudType>) {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        Data::parser_inline(3, CloudType::parser())
-            .then(
-                group((just("/"), CompassDirection::parser()))
-                    .map(|(_, dir)| dir)
-                    .repeated()
-                    .at_least(1)
-                    .collect::<Vec<_>>(),
-            )
-            .map(|(typ, dirs)| (dirs, typ))
+        let direction = || group((just("/"), CompassDirection::parser())).map(|(_, dir)| dir);
+
+        choice((
+            // An unknown type may be directly followed by the first direction, eg. `///SW`
+            just("///")
+                .ignore_then(CompassDirection::parser())
+                .then(direction().repeated().collect::<Vec<_>>())
+                .map(|(first, mut dirs)| {
+                    dirs.insert(0, first);
+                    (dirs, Data::Unknown)
+                }),
+            Data::parser_inline(3, CloudType::parser())
+                .then(direction().repeated().at_least(1).collect::<Vec<_>>())
+                .map(|(typ, dirs)| (dirs, typ)),
+        ))
     }
 }
 
@@ -49,3 +68,29 @@ impl Display for CloudType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity() {
+        assert!(CloudType::Cumulonimbus.severity() > CloudType::ToweringCumulus.severity());
+        assert!(CloudType::ToweringCumulus.severity() > CloudType::Normal.severity());
+
+        let mut types = vec![
+            CloudType::Cumulonimbus,
+            CloudType::Normal,
+            CloudType::ToweringCumulus,
+        ];
+        types.sort_by_key(CloudType::severity);
+        assert_eq!(
+            types,
+            vec![
+                CloudType::Normal,
+                CloudType::ToweringCumulus,
+                CloudType::Cumulonimbus
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use chumsky::prelude::*;
 
-use crate::{Data, traits::Parsable};
+use crate::traits::Parsable;
 
 /// Military airport colour code
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -24,17 +24,16 @@ pub enum ColourCode {
     Red,
 }
 
-impl Parsable for Data<ColourCode> {
+impl Parsable for ColourCode {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
-            just("///").map(|_| Data::Unknown),
-            just("BLU+").map(|_| Data::Known(ColourCode::BluePlus)),
-            just("BLU").map(|_| Data::Known(ColourCode::Blue)),
-            just("WHT").map(|_| Data::Known(ColourCode::White)),
-            just("GRN").map(|_| Data::Known(ColourCode::Green)),
-            just("YLO").map(|_| Data::Known(ColourCode::Yellow)),
-            just("AMB").map(|_| Data::Known(ColourCode::Amber)),
-            just("RED").map(|_| Data::Known(ColourCode::Red)),
+            just("BLU+").map(|_| ColourCode::BluePlus),
+            just("BLU").map(|_| ColourCode::Blue),
+            just("WHT").map(|_| ColourCode::White),
+            just("GRN").map(|_| ColourCode::Green),
+            just("YLO").map(|_| ColourCode::Yellow),
+            just("AMB").map(|_| ColourCode::Amber),
+            just("RED").map(|_| ColourCode::Red),
         ))
     }
 }
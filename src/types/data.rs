@@ -47,8 +47,31 @@ impl<T> Data<T> {
         }
     }
 
-    /// Apply a function to the contained value in this [`Data`].
-    pub(crate) fn map<F, O>(self, f: F) -> Data<O>
+    /// Returns the contained value, or `default` if it is unknown.
+    ///
+    /// ```rust
+    /// use metar::Data;
+    ///
+    /// assert_eq!(Data::Known(1013).unwrap_or(1000), 1013);
+    /// assert_eq!(Data::Unknown.unwrap_or(1000), 1000);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Data::Known(v) => v,
+            Data::Unknown => default,
+        }
+    }
+
+    /// Apply a function to the contained value in this [`Data`], leaving
+    /// unknown data unknown.
+    ///
+    /// ```rust
+    /// use metar::Data;
+    ///
+    /// assert_eq!(Data::Known(16).map(|t| t * 2), Data::Known(32));
+    /// assert_eq!(Data::<u32>::Unknown.map(|t| t * 2), Data::Unknown);
+    /// ```
+    pub fn map<F, O>(self, f: F) -> Data<O>
     where
         F: FnOnce(T) -> O,
     {
@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter};
+
+/// The flight category of a report, based on its ceiling and visibility
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlightCategory {
+    /// Low instrument flight rules: ceiling below 500ft or visibility below 1SM
+    LowInstrument,
+    /// Instrument flight rules: ceiling below 1000ft or visibility below 3SM
+    Instrument,
+    /// Marginal visual flight rules: ceiling up to 3000ft or visibility up to 5SM
+    MarginalVisual,
+    /// Visual flight rules
+    Visual,
+}
+
+impl Display for FlightCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FlightCategory::LowInstrument => "LIFR",
+            FlightCategory::Instrument => "IFR",
+            FlightCategory::MarginalVisual => "MVFR",
+            FlightCategory::Visual => "VFR",
+        })
+    }
+}
@@ -0,0 +1,51 @@
+/// Options controlling how a METAR is formatted by
+/// [`Metar::format_with`](crate::Metar::format_with).
+///
+/// The default options format a METAR exactly as [`Display`](std::fmt::Display)
+/// does.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormatOptions {
+    /// The unit to display visibilities in, or `None` to keep the reported unit
+    pub visibility_unit: Option<VisibilityUnit>,
+    /// The unit to display the pressure in, or `None` to keep the reported unit
+    pub pressure_unit: Option<PressureUnit>,
+    /// Whether trends should be included
+    pub include_trends: bool,
+    /// Whether remarks should be included
+    pub include_remarks: bool,
+    /// Whether to end the report with the `=` terminator
+    pub terminator: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            visibility_unit: None,
+            pressure_unit: None,
+            include_trends: true,
+            include_remarks: true,
+            terminator: false,
+        }
+    }
+}
+
+/// A unit of horizontal visibility
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VisibilityUnit {
+    /// Metres
+    Metres,
+    /// Statute miles
+    StatuteMiles,
+}
+
+/// A unit of pressure
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PressureUnit {
+    /// Hectopascals
+    Hectopascals,
+    /// Inches of mercury
+    InchesOfMercury,
+}
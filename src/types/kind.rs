@@ -10,6 +10,8 @@ pub enum Kind {
     Automatic,
     /// This METAR corrects a previously issued METAR
     Correction,
+    /// This METAR amends a previously issued METAR
+    Amended,
 }
 
 impl Display for Kind {
@@ -18,6 +20,7 @@ impl Display for Kind {
             Kind::Normal => Ok(()),
             Kind::Automatic => f.write_str("AUTO "),
             Kind::Correction => f.write_str("COR "),
+            Kind::Amended => f.write_str("AMD "),
         }
     }
 }
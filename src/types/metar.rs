@@ -1,15 +1,22 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Write as _},
+};
 
 use crate::{
-    CloudLayer, CloudType, Clouds, ColourCode, CompassDirection, Data, Kind, MetarError, Pressure,
-    RunwayCondition, RunwayVisualRange, SeaCondition, Time, Trend, VerticalVisibility, Visibility,
-    Weather, WeatherCondition, Wind, WindDirection, WindSpeed, WindshearWarnings,
+    CeilingCategory, CloudCover, CloudDensity, CloudLayer, CloudType, Clouds, ColourCode,
+    CompassDirection, Data, ErrorVariant, FlightCategory, FormatOptions, Kind, MetarError,
+    MetarParseError, Pressure, Remarks, RunwayCondition, RunwayVisualRange, SeaCondition, Time,
+    Trend, VerticalVisibility, Visibility, Weather, WeatherCondition, WeatherIntensity, Wind,
+    WindDirection, WindSpeed, WindUnit, WindshearWarnings,
     parsers::{any_whitespace, some_whitespace, temperature},
     traits::Parsable,
 };
+
+use super::wind::{missing_gust_separator, varying};
 use chumsky::prelude::*;
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A complete METAR
 pub struct Metar {
@@ -19,10 +26,18 @@ pub struct Metar {
     pub time: Time,
     /// The kind of METAR, i.e. Normal, Automatic or Correction
     pub kind: Kind,
+    /// Whether this is a NIL report, which carries no observation. All
+    /// other fields are unknown or empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nil: bool,
     /// The current wind information
     pub wind: Wind,
     /// The current visibility
     pub visibility: Data<Visibility>,
+    /// Whether the visibility is marked as having no directional variation
+    /// available (`NDV`), as automated stations do
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_directional_variation: bool,
     /// If the visibility is reduced further in a specific direction,
     /// that will be covered here. If the direction is [`None`], it is
     /// reduced in a nonspecific direction.
@@ -43,6 +58,9 @@ pub struct Metar {
     pub dewpoint: Data<f32>,
     /// The current air pressure
     pub pressure: Pressure,
+    /// A second air pressure reading, if the pressure was also given in
+    /// another unit, e.g. `Q1013 A2992`
+    pub additional_pressure: Option<Pressure>,
     /// Military airport colour code
     pub colour_code: Option<Data<ColourCode>>,
     /// Additional recent weather conditions
@@ -59,202 +77,421 @@ pub struct Metar {
     pub clouds_in_vicinity: Vec<(Vec<CompassDirection>, Data<CloudType>)>,
     /// Remarks added on to the METAR
     pub remarks: Option<String>,
+    /// Whether the station needs maintenance, shown by a `$` at the very end
+    /// of the report
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub maintenance_indicator: bool,
+    /// The exact, trimmed, string this METAR was parsed from, if it was
+    /// parsed. This is ignored when comparing METARs.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub raw: Option<String>,
+    /// Problems recovered from whilst parsing, such as weather given an
+    /// intensity it may not have, or a corrupted gust when parsing leniently.
+    /// This is ignored when comparing METARs.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub warnings: Vec<ErrorVariant>,
+}
+
+impl PartialEq for Metar {
+    fn eq(&self, other: &Self) -> bool {
+        let Metar {
+            station,
+            time,
+            kind,
+            nil,
+            wind,
+            visibility,
+            no_directional_variation,
+            reduced_directional_visibility,
+            rvr,
+            clouds,
+            cloud_layers,
+            vert_visibility,
+            weather,
+            temperature,
+            dewpoint,
+            pressure,
+            additional_pressure,
+            colour_code,
+            recent_weather,
+            windshear_warnings,
+            sea_condition,
+            runway_conditions,
+            trends,
+            clouds_in_vicinity,
+            remarks,
+            maintenance_indicator,
+            raw: _,
+            warnings: _,
+        } = self;
+
+        *station == other.station
+            && *time == other.time
+            && *kind == other.kind
+            && *nil == other.nil
+            && *wind == other.wind
+            && *visibility == other.visibility
+            && *no_directional_variation == other.no_directional_variation
+            && *reduced_directional_visibility == other.reduced_directional_visibility
+            && *rvr == other.rvr
+            && *clouds == other.clouds
+            && *cloud_layers == other.cloud_layers
+            && *vert_visibility == other.vert_visibility
+            && *weather == other.weather
+            && *temperature == other.temperature
+            && *dewpoint == other.dewpoint
+            && *pressure == other.pressure
+            && *additional_pressure == other.additional_pressure
+            && *colour_code == other.colour_code
+            && *recent_weather == other.recent_weather
+            && *windshear_warnings == other.windshear_warnings
+            && *sea_condition == other.sea_condition
+            && *runway_conditions == other.runway_conditions
+            && *trends == other.trends
+            && *clouds_in_vicinity == other.clouds_in_vicinity
+            && *remarks == other.remarks
+            && *maintenance_indicator == other.maintenance_indicator
+    }
+}
+
+/// Options controlling how leniently a METAR is parsed.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Capture unrecognised text following the body of the METAR into the
+    /// remarks, even when it is not preceded by `RMK`. Some non-compliant
+    /// feeds append free text in this way, but enabling this can mask real
+    /// parsing errors.
+    pub remarks_without_rmk: bool,
+    /// Recover a wind gust whose `G` separator has been corrupted, e.g.
+    /// `25015928KT` meaning `25015G28KT`, when the result is plausible. A
+    /// warning is recorded in [`Metar::warnings`] when this happens.
+    /// Otherwise, parsing fails with [`ErrorVariant::MissingGustSeparator`].
+    pub gust_without_separator: bool,
 }
 
 impl Parsable for Metar {
-    #[allow(clippy::too_many_lines)]
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
-        fn method<'src>() -> impl Parser<'src, &'src str, Kind, extra::Err<crate::MetarError<'src>>>
-        {
-            choice((
-                just("AUTO")
-                    .map(|_| Kind::Automatic)
-                    .then_ignore(some_whitespace()),
-                just("COR")
-                    .map(|_| Kind::Correction)
-                    .then_ignore(some_whitespace()),
-                just("CCA")
-                    .map(|_| Kind::Correction)
-                    .then_ignore(some_whitespace()),
-                empty().map(|()| Kind::Normal),
-            ))
-        }
-        let station = regex("[A-Z0-9]{4}");
+        parser(ParseOptions::default())
+    }
+}
 
-        group((
-            just("METAR")
-                .then_ignore(some_whitespace())
-                .map(|_| ())
-                .or(empty()),
-            method(),
-            station.then_ignore(some_whitespace()),
-            Time::parser().then_ignore(some_whitespace()),
-            method(),
-            choice((
-                Wind::parser(),
-                empty().map(|()| Wind::Present {
-                    dir: WindDirection::Heading(Data::Unknown),
-                    speed: WindSpeed::Knots {
-                        speed: Data::Unknown,
-                        gusting: None,
-                    },
-                    varying: None,
-                }),
-            )),
-            choice((
-                Data::parser_inline(4, Visibility::parser()).then_ignore(some_whitespace()),
-                empty().map(|()| Data::Unknown),
-            )),
-            <(Option<CompassDirection>, Data<Visibility>) as Parsable>::parser()
-                .separated_by(some_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
-            RunwayVisualRange::parser()
-                .separated_by(some_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
-            choice((
-                just("SKC")
-                    .map(|_| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![]))
-                    .then_ignore(some_whitespace()),
-                just("CLR")
-                    .map(|_| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![]))
-                    .then_ignore(some_whitespace()),
-                group((
-                    Data::parser_inline(
-                        2,
-                        Weather::parser()
-                            .separated_by(some_whitespace())
-                            .collect::<Vec<_>>(),
-                    )
-                    .then_ignore(some_whitespace())
-                    .or(empty().map(|()| Data::Known(vec![]))),
-                    VerticalVisibility::parser()
-                        .map(Some)
-                        .then_ignore(some_whitespace())
-                        .or(empty().map(|()| None)),
-                    Clouds::parser(),
-                    CloudLayer::parser()
+/// A wind which is entirely unknown
+fn unknown_wind() -> Wind {
+    Wind::Present {
+        dir: WindDirection::Heading(Data::Unknown),
+        speed: WindSpeed::Knots {
+            speed: Data::Unknown,
+            gusting: None,
+        },
+        varying: None,
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn parser<'src>(
+    options: ParseOptions,
+) -> impl Parser<'src, &'src str, Metar, extra::Err<MetarError<'src>>> {
+    fn method<'src>() -> impl Parser<'src, &'src str, Kind, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("AUTO")
+                .map(|_| Kind::Automatic)
+                .then_ignore(some_whitespace()),
+            just("COR")
+                .map(|_| Kind::Correction)
+                .then_ignore(some_whitespace()),
+            just("CCA")
+                .map(|_| Kind::Correction)
+                .then_ignore(some_whitespace()),
+            just("AMD")
+                .map(|_| Kind::Amended)
+                .then_ignore(some_whitespace()),
+            empty().map(|()| Kind::Normal),
+        ))
+    }
+    let station = || regex("[A-Z0-9]{4}");
+    let prefix = || {
+        just("METAR")
+            .then_ignore(some_whitespace())
+            .map(|_| ())
+            .or(empty())
+    };
+    let rmk = just("RMK")
+        .then(none_of("=").repeated().collect::<String>())
+        .map(|(_, s)| Some(s.trim().to_string()));
+    let remarks = if options.remarks_without_rmk {
+        rmk.or(none_of("=")
+            .repeated()
+            .at_least(1)
+            .collect::<String>()
+            .map(|s| Some(s.trim().to_string())))
+            .or(empty().map(|()| None))
+            .boxed()
+    } else {
+        rmk.or(empty().map(|()| None)).boxed()
+    };
+
+    let nil = group((
+        prefix(),
+        method(),
+        station().then_ignore(some_whitespace()),
+        Time::parser().then_ignore(some_whitespace()),
+        method(),
+        just("NIL"),
+        any_whitespace(),
+        just("=").or_not(),
+    ))
+    .map(|((), early_kind, station, time, kind, _, (), _)| Metar {
+        kind: if early_kind == Kind::Normal {
+            kind
+        } else {
+            early_kind
+        },
+        nil: true,
+        ..Metar::from_parts(
+            station,
+            time,
+            unknown_wind(),
+            Pressure::Hectopascals(Data::Unknown),
+        )
+    });
+
+    let observation = group((
+        prefix(),
+        method(),
+        station().then_ignore(some_whitespace()),
+        Time::parser().then_ignore(some_whitespace()),
+        method(),
+        choice((
+            Wind::parser().map(|wind| (wind, None)),
+            missing_gust_separator(options.gust_without_separator).map(move |wind| {
+                (
+                    wind,
+                    options
+                        .gust_without_separator
+                        .then_some(ErrorVariant::MissingGustSeparator),
+                )
+            }),
+            // A variation without a wind is malformed, but is caught here
+            // so that it can be reported clearly.
+            varying()
+                .validate(|_, e, emitter| {
+                    emitter.emit(ErrorVariant::WindVaryingWithoutWind.into_err(e.span()));
+                    (unknown_wind(), None)
+                })
+                .then_ignore(some_whitespace()),
+            empty().map(|()| (unknown_wind(), None)),
+        )),
+        choice((
+            Data::parser_inline(4, Visibility::parser())
+                .then(just("NDV").or_not().map(|ndv| ndv.is_some()))
+                .then_ignore(some_whitespace()),
+            empty().map(|()| (Data::Unknown, false)),
+        )),
+        <(Option<CompassDirection>, Data<Visibility>) as Parsable>::parser()
+            .separated_by(some_whitespace())
+            .allow_trailing()
+            .collect::<Vec<_>>(),
+        RunwayVisualRange::parser()
+            .separated_by(some_whitespace())
+            .allow_trailing()
+            .collect::<Vec<_>>(),
+        choice((
+            just("SKC")
+                .map(|_| (Data::Known(vec![]), None, Clouds::SkyClear, vec![]))
+                .then_ignore(some_whitespace()),
+            just("CLR")
+                .map(|_| (Data::Known(vec![]), None, Clouds::Clear, vec![]))
+                .then_ignore(some_whitespace()),
+            group((
+                choice((
+                    // Unknown weather must stand alone, as `//` may also
+                    // be the unknown intensity of a weather group
+                    just("//")
+                        .then(some_whitespace().rewind())
+                        .map(|_| Data::Unknown),
+                    Weather::parser()
                         .separated_by(some_whitespace())
-                        .allow_trailing()
-                        .collect::<Vec<_>>(),
+                        .collect::<Vec<_>>()
+                        .map(Data::Known),
                 ))
-                .map(|(wx, vvis, clouds, layers)| (wx, vvis, clouds, layers)),
-                empty().map(|()| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![])),
-            )),
-            group((
-                Data::parser_inline(2, temperature()),
-                just("/"),
-                Data::parser_inline(2, temperature()).or(empty().map(|()| Data::Unknown)),
-            ))
-            .map(|(temp, _, dewp)| (temp, dewp))
-            .then_ignore(some_whitespace())
-            .or(empty().map(|()| (Data::Unknown, Data::Unknown))),
-            Pressure::parser()
                 .then_ignore(some_whitespace())
-                .or(empty().map(|()| Pressure::Hectopascals(Data::Unknown))),
-            choice((
-                just("RE")
-                    .then(Data::parser_inline(
-                        2,
-                        WeatherCondition::parser()
-                            .repeated()
-                            .at_least(1)
-                            .collect::<Vec<_>>(),
-                    ))
-                    .map(|(_, wx)| wx)
+                .or(empty().map(|()| Data::Known(vec![]))),
+                VerticalVisibility::parser()
+                    .map(Some)
+                    .then_ignore(some_whitespace())
+                    .or(empty().map(|()| None)),
+                Clouds::parser(),
+                CloudLayer::parser()
                     .separated_by(some_whitespace())
-                    .collect::<Vec<_>>()
-                    .then_ignore(some_whitespace()),
-                empty().map(|()| vec![]),
-            )),
-            Data::<ColourCode>::parser()
-                .map(Some)
-                .then_ignore(some_whitespace())
-                .or(empty().map(|()| None)),
-            WindshearWarnings::parser()
-                .map(Some)
-                .then_ignore(some_whitespace())
-                .or(empty().map(|()| None)),
-            RunwayCondition::parser()
-                .separated_by(some_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
-            SeaCondition::parser()
-                .map(Some)
-                .then_ignore(some_whitespace())
-                .or(empty().map(|()| None)),
-            Trend::parser()
-                .separated_by(any_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
-            <(Vec<CompassDirection>, Data<CloudType>) as Parsable>::parser()
-                .separated_by(some_whitespace())
-                .allow_trailing()
-                .collect::<Vec<_>>(),
-            just("RMK")
-                .then(none_of("=").repeated().collect::<String>())
-                .map(|(_, s)| Some(s.trim().to_string()))
-                .or(empty().map(|()| None)),
-            any_whitespace(),
-            choice((just("=").map(|_| ()), empty().map(|()| ()))),
+                    .allow_trailing()
+                    .collect::<Vec<_>>(),
+            ))
+            .map(|(wx, vvis, clouds, layers)| (wx, vvis, clouds, layers)),
+            empty().map(|()| (Data::Known(vec![]), None, Clouds::NoCloudDetected, vec![])),
+        )),
+        group((
+            Data::parser_inline(2, temperature()),
+            just("/"),
+            Data::parser_inline(2, temperature()).or(empty().map(|()| Data::Unknown)),
         ))
-        .map(
-            |(
-                (),
-                early_kind,
-                station,
+        .map(|(temp, _, dewp)| (temp, dewp))
+        .then_ignore(some_whitespace())
+        .or(empty().map(|()| (Data::Unknown, Data::Unknown))),
+        Pressure::parser()
+            .then_ignore(some_whitespace())
+            .or(empty().map(|()| Pressure::Hectopascals(Data::Unknown)))
+            .then(Pressure::parser().then_ignore(some_whitespace()).or_not()),
+        choice((
+            just("RE")
+                .then(Data::parser_inline(
+                    2,
+                    WeatherCondition::parser()
+                        .repeated()
+                        .at_least(1)
+                        .collect::<Vec<_>>(),
+                ))
+                .map(|(_, wx)| wx)
+                .separated_by(some_whitespace())
+                .collect::<Vec<_>>()
+                .then_ignore(some_whitespace()),
+            empty().map(|()| vec![]),
+        )),
+        Data::parser_inline(3, ColourCode::parser())
+            .map(Some)
+            .then_ignore(some_whitespace())
+            .or(empty().map(|()| None)),
+        WindshearWarnings::parser()
+            .map(Some)
+            .then_ignore(some_whitespace())
+            .or(empty().map(|()| None)),
+        RunwayCondition::parser()
+            .separated_by(some_whitespace())
+            .allow_trailing()
+            .collect::<Vec<_>>(),
+        SeaCondition::parser()
+            .map(Some)
+            .then_ignore(some_whitespace())
+            .or(empty().map(|()| None)),
+        Trend::parser()
+            .separated_by(any_whitespace())
+            .allow_trailing()
+            .collect::<Vec<_>>(),
+        <(Vec<CompassDirection>, Data<CloudType>) as Parsable>::parser()
+            .separated_by(some_whitespace())
+            .allow_trailing()
+            .collect::<Vec<_>>(),
+        just("$").then_ignore(any_whitespace()).or_not(),
+        remarks,
+        any_whitespace(),
+        choice((just("=").map(|_| ()), empty().map(|()| ()))),
+    ))
+    .map(
+        |(
+            (),
+            early_kind,
+            station,
+            time,
+            kind,
+            (wind, wind_warning),
+            (visibility, no_directional_variation),
+            reduced_directional_visibility,
+            rvr,
+            (weather, vert_visibility, clouds, cloud_layers),
+            (temperature, dewpoint),
+            (pressure, additional_pressure),
+            recent_weather,
+            colour_code,
+            windshear_warnings,
+            runway_conditions,
+            sea_condition,
+            trends,
+            clouds_in_vicinity,
+            maintenance_indicator,
+            remarks,
+            (),
+            (),
+        )| {
+            // Recent weather is sometimes given amongst the current weather,
+            // so is moved to where it belongs.
+            let mut early_recent_weather = vec![];
+            let weather = weather.map(|weather| {
+                weather
+                    .into_iter()
+                    .filter_map(|wx| {
+                        if wx.intensity == WeatherIntensity::Recent {
+                            early_recent_weather.push(Data::Known(wx.conditions));
+                            None
+                        } else {
+                            Some(wx)
+                        }
+                    })
+                    .collect()
+            });
+            early_recent_weather.extend(recent_weather);
+            let recent_weather = early_recent_weather;
+
+            // The maintenance indicator is given after any remarks
+            let mut maintenance_indicator = maintenance_indicator.is_some();
+            let remarks = remarks.map(|rmk| match rmk.strip_suffix('$') {
+                Some(rest) if rest.is_empty() || rest.ends_with(char::is_whitespace) => {
+                    maintenance_indicator = true;
+                    rest.trim_end().to_string()
+                }
+                _ => rmk,
+            });
+
+            let mut metar = Metar {
+                station: station.to_string(),
                 time,
-                kind,
+                kind: if early_kind == Kind::Normal {
+                    kind
+                } else {
+                    early_kind
+                },
+                nil: false,
                 wind,
                 visibility,
+                no_directional_variation,
                 reduced_directional_visibility,
                 rvr,
-                (weather, vert_visibility, clouds, cloud_layers),
-                (temperature, dewpoint),
+                weather,
+                vert_visibility,
+                clouds,
+                cloud_layers,
+                temperature,
+                dewpoint,
                 pressure,
-                recent_weather,
+                additional_pressure,
                 colour_code,
+                recent_weather,
                 windshear_warnings,
-                runway_conditions,
                 sea_condition,
+                runway_conditions,
                 trends,
                 clouds_in_vicinity,
                 remarks,
-                (),
-                (),
-            )| {
-                Metar {
-                    station: station.to_string(),
-                    time,
-                    kind: if early_kind == Kind::Normal {
-                        kind
-                    } else {
-                        early_kind
-                    },
-                    wind,
-                    visibility,
-                    reduced_directional_visibility,
-                    rvr,
-                    weather,
-                    vert_visibility,
-                    clouds,
-                    cloud_layers,
-                    temperature,
-                    dewpoint,
-                    pressure,
-                    colour_code,
-                    recent_weather,
-                    windshear_warnings,
-                    sea_condition,
-                    runway_conditions,
-                    trends,
-                    clouds_in_vicinity,
-                    remarks,
-                }
-            },
-        )
-    }
+                maintenance_indicator,
+                raw: None,
+                warnings: wind_warning.into_iter().collect(),
+            };
+
+            // Weather with an intensity it may not have, e.g. `+FG`, is kept
+            // as given, but noted.
+            let invalid_intensities = metar
+                .all_weather()
+                .filter(|wx| !wx.has_valid_intensity())
+                .count();
+            metar.warnings.extend(std::iter::repeat_n(
+                ErrorVariant::InvalidWeatherIntensity,
+                invalid_intensities,
+            ));
+            metar
+        },
+    );
+
+    // Every group of an observation is optional, so it must be made to reach
+    // the end of the input for a NIL report to be tried
+    choice((observation.then_ignore(end()), nil))
 }
 
 impl Metar {
@@ -264,27 +501,991 @@ impl Metar {
     ///
     /// Returns a [`MetarError`] if parsing failed.
     pub fn parse(data: &str) -> Result<Self, Vec<MetarError<'_>>> {
-        <Metar as Parsable>::parse(data).map_err(|v| {
+        Metar::parse_with(data, ParseOptions::default())
+    }
+
+    /// Parse a string into a METAR, returning an owned error which can be
+    /// easily propagated, e.g. with `?`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MetarParseError`] if parsing failed.
+    pub fn parse_report(data: &str) -> Result<Self, MetarParseError> {
+        Ok(Metar::parse(data)?)
+    }
+
+    /// Parse a string into a METAR, using the given [`ParseOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MetarError`] if parsing failed.
+    pub fn parse_with(data: &str, options: ParseOptions) -> Result<Self, Vec<MetarError<'_>>> {
+        let mut metar = parser(options).parse(data).into_result().map_err(|v| {
             v.into_iter()
                 .map(|mut e| {
                     e.string = data;
                     e
                 })
                 .collect::<Vec<_>>()
+        })?;
+        metar.raw = Some(data.trim().to_string());
+        Ok(metar)
+    }
+
+    /// Construct a minimal METAR from a station, time, wind and pressure.
+    ///
+    /// Everything else is left unknown or empty, which is useful for quickly
+    /// building fixtures.
+    ///
+    /// ```rust
+    /// use metar::{Data, Metar, Pressure, Time, Wind};
+    ///
+    /// let metar = Metar::from_parts(
+    ///     "EGLL",
+    ///     Time { date: 1, hour: 12, minute: 20, second: None },
+    ///     Wind::Calm,
+    ///     Pressure::Hectopascals(Data::Known(1013)),
+    /// );
+    /// assert_eq!(metar.to_string(), "EGLL 011220Z CALM //// NCD ///// Q1013");
+    /// assert_eq!(Metar::parse(&metar.to_string()), Ok(metar));
+    /// ```
+    #[must_use]
+    pub fn from_parts(station: &str, time: Time, wind: Wind, pressure: Pressure) -> Metar {
+        Metar {
+            station: station.to_string(),
+            time,
+            kind: Kind::Normal,
+            nil: false,
+            wind,
+            visibility: Data::Unknown,
+            no_directional_variation: false,
+            reduced_directional_visibility: vec![],
+            rvr: vec![],
+            clouds: Clouds::NoCloudDetected,
+            cloud_layers: vec![],
+            vert_visibility: None,
+            weather: Data::Known(vec![]),
+            temperature: Data::Unknown,
+            dewpoint: Data::Unknown,
+            pressure,
+            additional_pressure: None,
+            colour_code: None,
+            recent_weather: vec![],
+            windshear_warnings: None,
+            sea_condition: None,
+            runway_conditions: vec![],
+            trends: vec![],
+            clouds_in_vicinity: vec![],
+            remarks: None,
+            maintenance_indicator: false,
+            raw: None,
+            warnings: vec![],
+        }
+    }
+
+    /// Decode the groups this library understands from the remarks, if
+    /// there are any.
+    #[must_use]
+    pub fn decoded_remarks(&self) -> Option<Remarks> {
+        let mut remarks = self
+            .remarks
+            .as_deref()
+            .and_then(|rmk| <Remarks as Parsable>::parse(rmk).ok())?;
+
+        if let Some(peak_wind) = &mut remarks.peak_wind
+            && let Wind::Present { speed, .. } = self.wind
+            && let Some(unit) = speed.unit()
+        {
+            peak_wind.unit = unit;
+        }
+
+        Some(remarks)
+    }
+
+    /// The remarks split into whitespace separated tokens, for simple
+    /// scanning. Empty if there are no remarks.
+    #[must_use]
+    pub fn remark_tokens(&self) -> Vec<&str> {
+        self.remarks
+            .as_deref()
+            .map(|rmk| rmk.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether this report was produced by an automated station, either
+    /// marked `AUTO` or with an `AO1` or `AO2` remark.
+    #[must_use]
+    pub fn is_automated(&self) -> bool {
+        self.kind == Kind::Automatic
+            || self
+                .remark_tokens()
+                .iter()
+                .any(|token| matches!(*token, "AO1" | "AO2"))
+    }
+
+    /// Replace the whole degree temperature and dewpoint with the tenths
+    /// precision values from the remarks (`Txxxxxxxx`), if given.
+    #[must_use]
+    pub fn with_precise_temperatures(mut self) -> Metar {
+        if let Some(precise) = self
+            .decoded_remarks()
+            .and_then(|rmk| rmk.precise_temperature)
+        {
+            self.temperature = Data::Known(precise.temperature);
+            if let Some(dewpoint) = precise.dewpoint {
+                self.dewpoint = Data::Known(dewpoint);
+            }
+        }
+        self
+    }
+
+    /// The temperature, falling back to the tenths precision value from the
+    /// remarks (`Txxxxxxxx`) if the temperature group is unknown, as some
+    /// stations report `//` in the body.
+    #[must_use]
+    pub fn effective_temperature(&self) -> Data<f32> {
+        match self.temperature {
+            Data::Known(temperature) => Data::Known(temperature),
+            Data::Unknown => self
+                .decoded_remarks()
+                .and_then(|rmk| rmk.precise_temperature)
+                .map_or(Data::Unknown, |precise| Data::Known(precise.temperature)),
+        }
+    }
+
+    /// Whether aircraft on the ground are likely to need de-icing.
+    ///
+    /// This is a heuristic, and true if any of:
+    /// - freezing precipitation (`FZ` with `RA`, `DZ` or `UP`) is reported
+    ///   at the station
+    /// - the temperature is at or below 0°C and precipitation or fog is
+    ///   reported at the station
+    /// - the remarks report any ice accretion (`Ixxxx`)
+    #[must_use]
+    pub fn needs_deicing(&self) -> bool {
+        let weather = match &self.weather {
+            Data::Known(weather) => weather.as_slice(),
+            Data::Unknown => &[],
+        };
+        let at_station = || {
+            weather
+                .iter()
+                .filter(|wx| wx.intensity != WeatherIntensity::InVicinity)
+        };
+
+        let freezing_precipitation = at_station().any(|wx| {
+            wx.conditions.contains(&WeatherCondition::Freezing)
+                && wx.conditions.iter().any(|c| {
+                    matches!(
+                        c,
+                        WeatherCondition::Rain
+                            | WeatherCondition::Drizzle
+                            | WeatherCondition::UnknownPrecipitation
+                    )
+                })
+        });
+
+        let subzero = matches!(self.effective_temperature(), Data::Known(t) if t <= 0.);
+        let moisture = at_station().any(|wx| {
+            wx.conditions.iter().any(|c| {
+                matches!(
+                    c,
+                    WeatherCondition::Rain
+                        | WeatherCondition::Drizzle
+                        | WeatherCondition::Snow
+                        | WeatherCondition::SnowGrains
+                        | WeatherCondition::IceCrystals
+                        | WeatherCondition::IcePellets
+                        | WeatherCondition::Hail
+                        | WeatherCondition::SnowPelletsOrSmallHail
+                        | WeatherCondition::UnknownPrecipitation
+                        | WeatherCondition::Fog
+                )
+            })
+        });
+
+        let ice_accretion = self.decoded_remarks().is_some_and(|rmk| {
+            [
+                rmk.ice_accretion_1hr_in,
+                rmk.ice_accretion_3hr_in,
+                rmk.ice_accretion_6hr_in,
+            ]
+            .into_iter()
+            .any(|ice| matches!(ice, Some(Data::Known(ice)) if ice > 0.))
+        });
+
+        freezing_precipitation || (subzero && moisture) || ice_accretion
+    }
+
+    /// How complete this report is, from 0.0 (every core field is unknown) to
+    /// 1.0 (every core field is known).
+    ///
+    /// The eight core fields are weighted equally: wind direction, wind
+    /// speed, visibility, present weather, clouds, temperature, dewpoint and
+    /// pressure. Calm and variable winds count as a known direction, and the
+    /// clouds are known only if every layer has a known density and height.
+    #[must_use]
+    pub fn observation_complete(&self) -> f32 {
+        let (direction, speed) = match self.wind {
+            Wind::Calm => (true, true),
+            Wind::Present { dir, speed, .. } => (
+                !matches!(dir, WindDirection::Heading(Data::Unknown)),
+                !matches!(
+                    speed,
+                    WindSpeed::Knots {
+                        speed: Data::Unknown,
+                        ..
+                    } | WindSpeed::MetresPerSecond {
+                        speed: Data::Unknown,
+                        ..
+                    } | WindSpeed::KilometresPerHour {
+                        speed: Data::Unknown,
+                        ..
+                    }
+                ),
+            ),
+        };
+        let clouds = self.cloud_layers.iter().all(|layer| {
+            matches!(layer.density, Data::Known(_)) && matches!(layer.height, Data::Known(_))
+        });
+        let pressure = !matches!(
+            self.pressure,
+            Pressure::Hectopascals(Data::Unknown) | Pressure::InchesOfMercury(Data::Unknown)
+        );
+
+        let known: [bool; 8] = [
+            direction,
+            speed,
+            matches!(self.visibility, Data::Known(_)),
+            matches!(self.weather, Data::Known(_)),
+            clouds,
+            matches!(self.temperature, Data::Known(_)),
+            matches!(self.dewpoint, Data::Known(_)),
+            pressure,
+        ];
+        known.iter().map(|&k| f32::from(u8::from(k))).sum::<f32>() / 8.
+    }
+
+    /// The visibility, with CAVOK resolved to 10km so it needn't be treated
+    /// specially.
+    #[must_use]
+    pub fn resolved_visibility(&self) -> Data<Visibility> {
+        match self.visibility {
+            Data::Known(Visibility::CAVOK) => Data::Known(Visibility::Metres(10_000)),
+            visibility => visibility,
+        }
+    }
+
+    /// The cloud state, with CAVOK resolved to no significant cloud so it
+    /// needn't be treated specially.
+    #[must_use]
+    pub fn resolved_clouds(&self) -> Clouds {
+        match self.visibility {
+            Data::Known(Visibility::CAVOK) => Clouds::NoSignificantCloud,
+            _ => self.clouds,
+        }
+    }
+
+    /// The current weather which is operationally significant, as decided by
+    /// [`Weather::is_significant`].
+    #[must_use]
+    pub fn significant_weather(&self) -> Vec<&Weather> {
+        match &self.weather {
+            Data::Known(weather) => weather.iter().filter(|wx| wx.is_significant()).collect(),
+            Data::Unknown => vec![],
+        }
+    }
+
+    /// Iterate over all weather in the report: the current weather, then
+    /// any recent weather, then weather from the trends. Unknown weather
+    /// (`//`) is skipped.
+    ///
+    /// Recent weather is only stored as a list of conditions, so it is
+    /// yielded as an owned [`Weather`] with [`WeatherIntensity::Recent`]. All
+    /// other weather is borrowed.
+    pub fn all_weather(&self) -> impl Iterator<Item = Cow<'_, Weather>> {
+        let current = match &self.weather {
+            Data::Known(wx) => wx.as_slice(),
+            Data::Unknown => &[],
+        };
+        let recent = self.recent_weather.iter().filter_map(|wx| match wx {
+            Data::Known(conditions) => Some(Cow::Owned(Weather {
+                intensity: WeatherIntensity::Recent,
+                conditions: conditions.clone(),
+            })),
+            Data::Unknown => None,
+        });
+        let trends = self.trends.iter().flat_map(|trend| match trend {
+            Trend::Becoming(cond) | Trend::Temporarily(cond) => cond.weather.as_slice(),
+            Trend::NoSignificantChanges | Trend::NoSignificantWeather => &[],
+        });
+
+        current
+            .iter()
+            .map(Cow::Borrowed)
+            .chain(recent)
+            .chain(trends.map(Cow::Borrowed))
+    }
+
+    /// The unit the wind was reported in.
+    ///
+    /// Returns [`None`] for `CALM`, which has no unit, and for
+    /// [`WindSpeed::Greater`], which does not retain its unit.
+    #[must_use]
+    pub fn wind_unit(&self) -> Option<WindUnit> {
+        match self.wind {
+            Wind::Calm => None,
+            Wind::Present { speed, .. } => speed.unit(),
+        }
+    }
+
+    /// Whether the wind direction is variable (`VRB`), or varying between two
+    /// headings, e.g. `140V220`.
+    #[must_use]
+    pub fn wind_is_variable(&self) -> bool {
+        matches!(
+            self.wind,
+            Wind::Present {
+                dir: WindDirection::Variable,
+                ..
+            } | Wind::Present {
+                varying: Some(_),
+                ..
+            }
+        )
+    }
+
+    /// Whether gusts are reported, even if their speed is unknown.
+    #[must_use]
+    pub fn wind_is_gusting(&self) -> bool {
+        matches!(
+            self.wind,
+            Wind::Present {
+                speed: WindSpeed::Knots {
+                    gusting: Some(_),
+                    ..
+                } | WindSpeed::MetresPerSecond {
+                    gusting: Some(_),
+                    ..
+                } | WindSpeed::KilometresPerHour {
+                    gusting: Some(_),
+                    ..
+                },
+                ..
+            }
+        )
+    }
+
+    /// The difference between the gust and sustained wind speed, in the
+    /// unit the wind was reported in.
+    ///
+    /// Returns [`None`] if the wind is not gusting, either speed is unknown,
+    /// or the gust is lower than the sustained speed.
+    #[must_use]
+    pub fn gust_spread(&self) -> Option<u32> {
+        let Wind::Present {
+            speed:
+                WindSpeed::Knots {
+                    speed: Data::Known(speed),
+                    gusting: Some(Data::Known(gust)),
+                }
+                | WindSpeed::MetresPerSecond {
+                    speed: Data::Known(speed),
+                    gusting: Some(Data::Known(gust)),
+                }
+                | WindSpeed::KilometresPerHour {
+                    speed: Data::Known(speed),
+                    gusting: Some(Data::Known(gust)),
+                },
+            ..
+        } = self.wind
+        else {
+            return None;
+        };
+        gust.checked_sub(speed)
+    }
+
+    /// Whether the gusts exceed the sustained wind speed by at least
+    /// `threshold` knots, converting from the unit the wind was reported in.
+    /// Operations manuals commonly use a spread of 10 kt.
+    ///
+    /// Returns `false` if the wind is not gusting or either speed is unknown.
+    #[must_use]
+    pub fn is_gusty(&self, threshold: u32) -> bool {
+        let (Some(spread), Some(unit)) = (self.gust_spread(), self.wind_unit()) else {
+            return false;
+        };
+        f64::from(unit.to_knots(spread)) >= f64::from(threshold)
+    }
+
+    /// The cloud layers in this report, as given. Empty if no layers are
+    /// given, including for `NCD`, `NSC`, `SKC`, `CLR` and CAVOK.
+    #[must_use]
+    pub fn all_clouds(&self) -> Vec<CloudLayer> {
+        if self.clouds == Clouds::CloudLayers {
+            self.cloud_layers.clone()
+        } else {
+            vec![]
+        }
+    }
+
+    /// A uniform list of the cloud layers in this report, sorted by height
+    /// from lowest to highest with duplicates removed.
+    ///
+    /// `NCD`, `NSC`, `SKC` and `CLR` all produce an empty list. Layers with an
+    /// unknown density or height are left out, and an unknown cloud type is
+    /// treated as [`CloudType::Normal`].
+    #[must_use]
+    pub fn clouds_summary(&self) -> Vec<(CloudCover, u32, CloudType)> {
+        if self.clouds != Clouds::CloudLayers {
+            return vec![];
+        }
+
+        let mut layers = vec![];
+        for layer in &self.cloud_layers {
+            if let (Data::Known(density), Data::Known(height)) = (layer.density, layer.height) {
+                let kind = match layer.kind {
+                    Data::Known(kind) => kind,
+                    Data::Unknown => CloudType::Normal,
+                };
+                let layer = (CloudCover::from(density), height, kind);
+                if !layers.contains(&layer) {
+                    layers.push(layer);
+                }
+            }
+        }
+        layers.sort_by_key(|(_, height, _)| *height);
+        layers
+    }
+
+    /// Compare only the observed conditions of two reports: the wind,
+    /// visibility, clouds, temperature, dewpoint, pressure and weather.
+    ///
+    /// Everything else, including the station, time, trends and remarks, is
+    /// ignored.
+    #[must_use]
+    pub fn core_eq(&self, other: &Metar) -> bool {
+        self.wind == other.wind
+            && self.visibility == other.visibility
+            && self.clouds == other.clouds
+            && self.cloud_layers == other.cloud_layers
+            && self.vert_visibility == other.vert_visibility
+            && self.temperature == other.temperature
+            && self.dewpoint == other.dewpoint
+            && self.pressure == other.pressure
+            && self.weather == other.weather
+    }
+
+    /// All runways mentioned in the runway visual ranges, runway conditions
+    /// and windshear warnings, without duplicates, e.g. `24L`.
+    #[must_use]
+    pub fn runways_mentioned(&self) -> Vec<String> {
+        let windshear_runways = match &self.windshear_warnings {
+            Some(WindshearWarnings::Runways(runways)) => runways.as_slice(),
+            _ => &[],
+        };
+
+        let mut runways: Vec<String> = vec![];
+        for runway in self
+            .rvr
+            .iter()
+            .map(|rvr| &rvr.runway)
+            .chain(self.runway_conditions.iter().map(|c| &c.runway_number))
+            .chain(windshear_runways)
+        {
+            if !runways.contains(runway) {
+                runways.push(runway.clone());
+            }
+        }
+        runways
+    }
+
+    /// The height of the ceiling in feet: the lowest broken or overcast
+    /// layer, or the vertical visibility if the sky is obscured.
+    ///
+    /// Returns [`None`] if there is no ceiling, or its height is unknown.
+    #[must_use]
+    pub fn ceiling(&self) -> Option<u32> {
+        let layers = self
+            .cloud_layers
+            .iter()
+            .filter(|layer| {
+                matches!(
+                    layer.density,
+                    Data::Known(CloudDensity::Broken | CloudDensity::Overcast)
+                )
+            })
+            .filter_map(|layer| match layer.height_feet() {
+                Data::Known(height) => Some(height),
+                Data::Unknown => None,
+            });
+        let vertical_visibility = match self.vert_visibility {
+            Some(VerticalVisibility::Distance(vv)) => Some(vv * 100),
+            _ => None,
+        };
+        layers.chain(vertical_visibility).min()
+    }
+
+    /// The band the [`Metar::ceiling`] falls into. A ceiling of unknown height
+    /// is treated as no ceiling.
+    #[must_use]
+    pub fn ceiling_category(&self) -> CeilingCategory {
+        CeilingCategory::from_feet(self.ceiling())
+    }
+
+    /// The pressure altitude in feet at a field of the given elevation in
+    /// feet, approximated from the altimeter setting as
+    /// `(29.92 - altimeter) * 1000 + elevation`.
+    ///
+    /// Returns [`None`] if the pressure is unknown.
+    #[must_use]
+    pub fn pressure_altitude(&self, field_elevation_ft: f32) -> Option<f32> {
+        let altimeter = self.pressure.inches_of_mercury()?;
+        Some((29.92 - altimeter) * 1000. + field_elevation_ft)
+    }
+
+    /// The estimated height of the freezing level in feet above the station,
+    /// assuming the temperature falls at a standard 2°C per 1000ft.
+    ///
+    /// Returns 0 if the temperature is already at or below freezing, and
+    /// [`None`] if it is unknown.
+    #[must_use]
+    pub fn estimated_freezing_level_ft(&self) -> Option<f32> {
+        let Data::Known(temperature) = self.effective_temperature() else {
+            return None;
+        };
+        Some(temperature.max(0.) / 2. * 1000.)
+    }
+
+    /// The relative humidity as a percentage, calculated from the
+    /// temperature and dewpoint.
+    #[must_use]
+    pub fn relative_humidity(&self) -> Option<f32> {
+        let (Data::Known(temperature), Data::Known(dewpoint)) = (self.temperature, self.dewpoint)
+        else {
+            return None;
+        };
+        // Magnus formula
+        let saturation = |t: f32| (17.625 * t / (243.04 + t)).exp();
+        Some(100. * saturation(dewpoint) / saturation(temperature))
+    }
+
+    /// The flight category of this report, based on the ceiling and
+    /// visibility.
+    ///
+    /// Returns [`None`] if the visibility is unknown.
+    #[must_use]
+    pub fn flight_category(&self) -> Option<FlightCategory> {
+        let Data::Known(visibility) = self.visibility else {
+            return None;
+        };
+        let visibility = visibility.statute_miles().unwrap_or(f32::INFINITY);
+        let ceiling = self.ceiling().unwrap_or(u32::MAX);
+
+        Some(if ceiling < 500 || visibility < 1. {
+            FlightCategory::LowInstrument
+        } else if ceiling < 1000 || visibility < 3. {
+            FlightCategory::Instrument
+        } else if ceiling <= 3000 || visibility <= 5. {
+            FlightCategory::MarginalVisual
+        } else {
+            FlightCategory::Visual
+        })
+    }
+
+    /// Whether the prevailing visibility is at least the given number of
+    /// metres, or [`None`] if the visibility is unknown. CAVOK counts as 10km.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "visibility thresholds are far below f32 precision limits"
+    )]
+    pub fn visibility_is_at_least(&self, threshold_m: u32) -> Option<bool> {
+        match self.visibility {
+            Data::Known(visibility) => Some(visibility.metres() >= threshold_m as f32),
+            Data::Unknown => None,
+        }
+    }
+
+    /// Whether the change from the `previous` observation to this one meets
+    /// the ICAO criteria for issuing a SPECI:
+    ///
+    /// - the visibility passing through 800, 1500, 3000 or 5000 metres
+    /// - the ceiling passing through 100, 200, 500, 1000 or 1500 feet
+    /// - the onset, cessation or change in intensity of significant weather,
+    ///   as decided by [`Weather::is_significant`], e.g. thunderstorms
+    ///
+    /// Unknown visibilities are not compared, and no ceiling is treated as an
+    /// unlimited ceiling.
+    #[must_use]
+    pub fn triggers_speci(&self, previous: &Metar) -> bool {
+        const VISIBILITY_THRESHOLDS: [f32; 4] = [800., 1500., 3000., 5000.];
+        const CEILING_THRESHOLDS: [u32; 5] = [100, 200, 500, 1000, 1500];
+
+        let visibility = match (self.visibility, previous.visibility) {
+            (Data::Known(now), Data::Known(before)) => {
+                let (now, before) = (now.metres(), before.metres());
+                VISIBILITY_THRESHOLDS
+                    .iter()
+                    .any(|threshold| (now < *threshold) != (before < *threshold))
+            }
+            _ => false,
+        };
+
+        let (now, before) = (
+            self.ceiling().unwrap_or(u32::MAX),
+            previous.ceiling().unwrap_or(u32::MAX),
+        );
+        let ceiling = CEILING_THRESHOLDS
+            .iter()
+            .any(|threshold| (now < *threshold) != (before < *threshold));
+
+        let weather = self.significant_weather() != previous.significant_weather();
+
+        visibility || ceiling || weather
+    }
+
+    /// The wind as a vector of its eastward and northward components (`u`
+    /// and `v`) in knots, following the meteorological convention that the
+    /// wind direction is where the wind blows from. A westerly wind therefore
+    /// has a positive eastward component.
+    ///
+    /// Calm winds are the zero vector. Returns [`None`] if the direction is
+    /// variable or the direction or speed is unknown.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "headings are far below f32 precision limits"
+    )]
+    pub fn wind_vector_knots(&self) -> Option<(f32, f32)> {
+        match self.wind {
+            Wind::Calm => Some((0., 0.)),
+            Wind::Present {
+                dir: WindDirection::Heading(Data::Known(dir)),
+                speed,
+                ..
+            } => {
+                let speed = speed.knots()?;
+                let (sin, cos) = (dir as f32).to_radians().sin_cos();
+                Some((-speed * sin, -speed * cos))
+            }
+            Wind::Present { .. } => None,
+        }
+    }
+
+    /// A concise one-line description of this METAR in plain English, e.g.
+    /// `Wind 240° at 15 kt, visibility 6 km, broken cloud at 900 ft,
+    /// 16°C/14°C, QNH 1006 hPa.`
+    ///
+    /// Unknown values are described as unknown, and weather is given as its
+    /// code, e.g. `-RA`.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn summary(&self) -> String {
+        let mut parts = vec![];
+
+        parts.push(match self.wind {
+            Wind::Calm => "Wind calm".to_string(),
+            Wind::Present {
+                dir,
+                speed,
+                varying,
+            } => {
+                let mut wind = match dir {
+                    WindDirection::Heading(Data::Known(hdg)) => format!("Wind {hdg:03}°"),
+                    WindDirection::Heading(Data::Unknown) => {
+                        "Wind from unknown direction".to_string()
+                    }
+                    WindDirection::Variable => "Wind variable".to_string(),
+                };
+                let unit = match speed.unit() {
+                    Some(WindUnit::MetresPerSecond) => "m/s",
+                    Some(WindUnit::KilometresPerHour) => "km/h",
+                    Some(WindUnit::Knots) | None => "kt",
+                };
+                let value = |v: Data<u32>| match v {
+                    Data::Known(v) => format!("{v} {unit}"),
+                    Data::Unknown => "unknown speed".to_string(),
+                };
+                match speed {
+                    WindSpeed::Knots { speed, gusting }
+                    | WindSpeed::MetresPerSecond { speed, gusting }
+                    | WindSpeed::KilometresPerHour { speed, gusting } => {
+                        let _ = write!(wind, " at {}", value(speed));
+                        if let Some(gusting) = gusting {
+                            let _ = write!(wind, " gusting {}", value(gusting));
+                        }
+                    }
+                    WindSpeed::AtLeast { speed, .. } => {
+                        let _ = write!(wind, " at {speed} {unit} or more");
+                    }
+                    WindSpeed::Greater => wind += " at more than 99 kt",
+                }
+                if let Some((from, to)) = varying {
+                    let _ = write!(
+                        wind,
+                        ", varying {}°-{}°",
+                        from.to_opt_string(3),
+                        to.to_opt_string(3)
+                    );
+                }
+                wind
+            }
+        });
+
+        parts.push(match self.visibility {
+            Data::Known(Visibility::CAVOK) => "CAVOK".to_string(),
+            Data::Known(Visibility::Metres(9999)) => "visibility 10 km or more".to_string(),
+            Data::Known(Visibility::Metres(m)) if m >= 5000 && m % 1000 == 0 => {
+                format!("visibility {} km", m / 1000)
+            }
+            Data::Known(Visibility::Metres(m)) => format!("visibility {m} m"),
+            Data::Known(Visibility::StatuteMiles(sm)) => format!("visibility {sm} SM"),
+            Data::Known(Visibility::MoreThanStatuteMiles(sm)) => {
+                format!("visibility more than {sm} SM")
+            }
+            Data::Unknown => "visibility unknown".to_string(),
+        });
+
+        match &self.weather {
+            Data::Known(weather) if !weather.is_empty() => {
+                let codes: Vec<_> = weather.iter().map(ToString::to_string).collect();
+                parts.push(format!("weather {}", codes.join(" ")));
+            }
+            Data::Known(_) => {}
+            Data::Unknown => parts.push("weather unknown".to_string()),
+        }
+
+        if self.visibility != Data::Known(Visibility::CAVOK) {
+            match self.clouds {
+                Clouds::NoCloudDetected => parts.push("no cloud detected".to_string()),
+                Clouds::SkyClear => parts.push("sky clear".to_string()),
+                Clouds::Clear => parts.push("no cloud below 12000 ft".to_string()),
+                Clouds::NoSignificantCloud => parts.push("no significant cloud".to_string()),
+                Clouds::CloudLayers => {}
+            }
+            for layer in &self.cloud_layers {
+                let density = match layer.density {
+                    Data::Known(CloudDensity::Few) => "few",
+                    Data::Known(CloudDensity::Scattered) => "scattered",
+                    Data::Known(CloudDensity::Broken) => "broken",
+                    Data::Known(CloudDensity::Overcast) => "overcast",
+                    Data::Unknown => "unknown",
+                };
+                let height = match layer.height_feet() {
+                    Data::Known(height) => format!("at {height} ft"),
+                    Data::Unknown => "at unknown height".to_string(),
+                };
+                let kind = match layer.kind {
+                    Data::Known(CloudType::Cumulonimbus) => " (cumulonimbus)",
+                    Data::Known(CloudType::ToweringCumulus) => " (towering cumulus)",
+                    _ => "",
+                };
+                parts.push(format!("{density} cloud {height}{kind}"));
+            }
+            match self.vert_visibility {
+                Some(VerticalVisibility::Distance(vv)) => {
+                    parts.push(format!("vertical visibility {} ft", vv * 100));
+                }
+                Some(VerticalVisibility::ReducedByUnknownAmount) => {
+                    parts.push("vertical visibility unknown".to_string());
+                }
+                None => {}
+            }
+        }
+
+        let temperature = |t: Data<f32>| match t {
+            Data::Known(t) => format!("{t}°C"),
+            Data::Unknown => "unknown".to_string(),
+        };
+        parts.push(format!(
+            "{}/{}",
+            temperature(self.temperature),
+            temperature(self.dewpoint)
+        ));
+
+        parts.push(match self.pressure {
+            Pressure::Hectopascals(Data::Known(qnh)) => format!("QNH {qnh} hPa"),
+            Pressure::InchesOfMercury(Data::Known(inhg)) => format!("altimeter {inhg:.2} inHg"),
+            _ => "pressure unknown".to_string(),
+        });
+
+        parts.join(", ") + "."
+    }
+
+    /// Render a human readable summary of this METAR as a multi-line table,
+    /// e.g. for command line tools.
+    #[must_use]
+    pub fn to_ascii_table(&self) -> String {
+        let unknown = || "Unknown".to_string();
+        let wind = match self.wind {
+            Wind::Calm => "Calm".to_string(),
+            Wind::Present {
+                dir,
+                speed,
+                varying,
+            } => {
+                let mut wind = match dir {
+                    WindDirection::Heading(Data::Known(hdg)) => format!("{hdg:03} deg"),
+                    WindDirection::Heading(Data::Unknown) => unknown(),
+                    WindDirection::Variable => "Variable".to_string(),
+                };
+                let unit = speed.unit().unwrap_or(WindUnit::Knots);
+                let value = |v: Data<u32>| match v {
+                    Data::Known(v) => format!("{v}{unit}"),
+                    Data::Unknown => format!("unknown {unit}"),
+                };
+                match speed {
+                    WindSpeed::Knots { speed, gusting }
+                    | WindSpeed::MetresPerSecond { speed, gusting }
+                    | WindSpeed::KilometresPerHour { speed, gusting } => {
+                        let _ = write!(wind, " at {}", value(speed));
+                        if let Some(gusting) = gusting {
+                            let _ = write!(wind, " gusting {}", value(gusting));
+                        }
+                    }
+                    WindSpeed::AtLeast { speed, unit } => {
+                        let _ = write!(wind, " at {speed}{unit} or more");
+                    }
+                    WindSpeed::Greater => wind += " at more than 99",
+                }
+                if let Some((from, to)) = varying {
+                    let _ = write!(
+                        wind,
+                        ", varying {}-{} deg",
+                        from.to_opt_string(3),
+                        to.to_opt_string(3)
+                    );
+                }
+                wind
+            }
+        };
+        let visibility = match self.visibility {
+            Data::Known(Visibility::Metres(m)) => format!("{m}m"),
+            Data::Known(vis) => vis.to_string(),
+            Data::Unknown => unknown(),
+        };
+        let temperature = |t: Data<f32>| match t {
+            Data::Known(t) => format!("{t}C"),
+            Data::Unknown => unknown(),
+        };
+        let pressure = match self.pressure {
+            Pressure::Hectopascals(Data::Known(qnh)) => format!("{qnh}hPa"),
+            Pressure::InchesOfMercury(Data::Known(inhg)) => format!("{inhg:.2}inHg"),
+            _ => unknown(),
+        };
+
+        let rows = [
+            ("Station", self.station.clone()),
+            (
+                "Time",
+                format!(
+                    "Day {}, {:02}:{:02}Z",
+                    self.time.date, self.time.hour, self.time.minute
+                ),
+            ),
+            ("Wind", wind),
+            ("Visibility", visibility),
+            (
+                "Ceiling",
+                self.ceiling()
+                    .map_or_else(|| "None".to_string(), |c| format!("{c}ft")),
+            ),
+            ("Temperature", temperature(self.temperature)),
+            ("Dewpoint", temperature(self.dewpoint)),
+            (
+                "Humidity",
+                self.relative_humidity()
+                    .map_or_else(unknown, |rh| format!("{rh:.0}%")),
+            ),
+            ("Pressure", pressure),
+            (
+                "Flight category",
+                self.flight_category()
+                    .map_or_else(unknown, |c| c.to_string()),
+            ),
+        ];
+
+        let key_width = rows.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+        let value_width = rows.iter().map(|(_, v)| v.len()).max().unwrap_or(0);
+        let border = format!(
+            "+-{}-+-{}-+\n",
+            "-".repeat(key_width),
+            "-".repeat(value_width)
+        );
+
+        let mut table = border.clone();
+        for (key, value) in rows {
+            let _ = writeln!(table, "| {key:key_width$} | {value:value_width$} |");
+        }
+        table += &border;
+        table
+    }
+
+    /// Format this METAR according to the given [`FormatOptions`], for
+    /// example to show visibilities and pressure in a user's preferred units.
+    #[must_use]
+    pub fn format_with(&self, opts: &FormatOptions) -> String {
+        let mut metar = self.clone();
+
+        if let Some(unit) = opts.visibility_unit {
+            metar.visibility = metar.visibility.map(|vis| vis.to_unit(unit));
+            for (_, vis) in &mut metar.reduced_directional_visibility {
+                *vis = vis.map(|vis| vis.to_unit(unit));
+            }
+            for trend in &mut metar.trends {
+                if let Trend::Becoming(cond) | Trend::Temporarily(cond) = trend {
+                    cond.visibility = cond.visibility.map(|vis| vis.to_unit(unit));
+                }
+            }
+        }
+        if let Some(unit) = opts.pressure_unit {
+            metar.pressure = metar.pressure.to_unit(unit);
+        }
+        if !opts.include_trends {
+            metar.trends.clear();
+        }
+        if !opts.include_remarks {
+            metar.remarks = None;
+        }
+
+        let mut formatted = metar.to_string();
+        if opts.terminator {
+            formatted.push('=');
+        }
+        formatted
+    }
+
+    /// Format this METAR followed by the `=` terminator, as some systems
+    /// require.
+    #[must_use]
+    pub fn to_string_with_equals(&self) -> String {
+        self.format_with(&FormatOptions {
+            terminator: true,
+            ..FormatOptions::default()
         })
     }
 }
 
 impl Display for Metar {
+    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.station)?;
         f.write_str(" ")?;
 
         write!(f, "{} ", self.time)?;
         self.kind.fmt(f)?;
+        if self.nil {
+            return f.write_str("NIL");
+        }
         write!(f, "{} ", self.wind)?;
 
-        write!(f, "{} ", self.visibility.to_opt_string(4))?;
+        let ndv = if self.no_directional_variation {
+            "NDV"
+        } else {
+            ""
+        };
+        write!(f, "{}{ndv} ", self.visibility.to_opt_string(4))?;
 
         for (dir, reduced_vis) in &self.reduced_directional_visibility {
             if let Some(dir) = dir {
@@ -348,13 +1549,19 @@ impl Display for Metar {
         )?;
 
         write!(f, " {}", self.pressure)?;
+        if let Some(pressure) = &self.additional_pressure {
+            write!(f, " {pressure}")?;
+        }
 
         for wx in &self.recent_weather {
             f.write_str(" RE")?;
-            if let Data::Known(wx_conditions) = wx {
-                for wx_condition in wx_conditions {
-                    write!(f, "{wx_condition}")?;
+            match wx {
+                Data::Known(wx_conditions) => {
+                    for wx_condition in wx_conditions {
+                        write!(f, "{wx_condition}")?;
+                    }
                 }
+                Data::Unknown => f.write_str("//")?,
             }
         }
 
@@ -362,14 +1569,29 @@ impl Display for Metar {
             write!(f, " {}", colour.to_opt_string(3))?;
         }
 
+        if let Some(windshear) = &self.windshear_warnings {
+            write!(f, " {windshear}")?;
+        }
+
         for trend in &self.trends {
             write!(f, " {trend}")?;
         }
 
+        for (dirs, kind) in &self.clouds_in_vicinity {
+            write!(f, " {}", kind.to_opt_string(3))?;
+            for dir in dirs {
+                write!(f, "/{dir}")?;
+            }
+        }
+
         if let Some(remarks) = &self.remarks {
             write!(f, " RMK {remarks}")?;
         }
 
+        if self.maintenance_indicator {
+            f.write_str(" $")?;
+        }
+
         Ok(())
     }
 }
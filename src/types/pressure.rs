@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter};
 
-use crate::{MetarError, traits::Parsable};
+use crate::{MetarError, PressureUnit, traits::Parsable};
 
 use super::Data;
 use chumsky::prelude::*;
@@ -22,9 +22,16 @@ impl Parsable for Pressure {
             .to_slice()
             .map(|d: &str| d.parse::<u16>().unwrap());
 
+        // Some feeds drop the leading zero from pressures below 1000hPa
+        let hectopascals = text::digits(10)
+            .at_least(3)
+            .at_most(4)
+            .to_slice()
+            .map(|d: &str| d.parse::<u16>().unwrap());
+
         choice((
             just("Q")
-                .then(Data::parser_inline(4, four_digits))
+                .then(Data::parser_inline(4, hectopascals))
                 .map(|(_, d)| Pressure::Hectopascals(d)),
             just("A")
                 .then(Data::parser_inline(4, four_digits))
@@ -33,6 +40,63 @@ impl Parsable for Pressure {
     }
 }
 
+/// The number of hectopascals in an inch of mercury
+const HECTOPASCALS_PER_INCH_OF_MERCURY: f32 = 33.8639;
+
+impl Pressure {
+    /// A known pressure in hectopascals, rounded to the nearest whole
+    /// hectopascal as it would be reported, e.g. `Q1013`.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "pressure is clamped to fit in the range of a u16"
+    )]
+    pub fn from_hectopascals(hpa: f32) -> Pressure {
+        Pressure::Hectopascals(Data::Known(hpa.round().clamp(0., 9999.) as u16))
+    }
+
+    /// A known pressure in inches of mercury, rounded to the nearest
+    /// hundredth of an inch as it would be reported, e.g. `A2992`.
+    #[must_use]
+    pub fn from_inches_of_mercury(inhg: f32) -> Pressure {
+        Pressure::InchesOfMercury(Data::Known((inhg * 100.).round().clamp(0., 9999.) / 100.))
+    }
+
+    /// This pressure in inches of mercury, or [`None`] if it is unknown.
+    #[must_use]
+    pub fn inches_of_mercury(&self) -> Option<f32> {
+        match self {
+            Pressure::Hectopascals(Data::Known(hpa)) => {
+                Some(f32::from(*hpa) / HECTOPASCALS_PER_INCH_OF_MERCURY)
+            }
+            Pressure::InchesOfMercury(Data::Known(inhg)) => Some(*inhg),
+            Pressure::Hectopascals(Data::Unknown) | Pressure::InchesOfMercury(Data::Unknown) => {
+                None
+            }
+        }
+    }
+
+    /// Convert this pressure into the given unit.
+    pub(crate) fn to_unit(self, unit: PressureUnit) -> Pressure {
+        match (self, unit) {
+            (Pressure::InchesOfMercury(Data::Known(inhg)), PressureUnit::Hectopascals) => {
+                Pressure::from_hectopascals(inhg * HECTOPASCALS_PER_INCH_OF_MERCURY)
+            }
+            (Pressure::InchesOfMercury(Data::Unknown), PressureUnit::Hectopascals) => {
+                Pressure::Hectopascals(Data::Unknown)
+            }
+            (Pressure::Hectopascals(Data::Known(hpa)), PressureUnit::InchesOfMercury) => {
+                Pressure::from_inches_of_mercury(f32::from(hpa) / HECTOPASCALS_PER_INCH_OF_MERCURY)
+            }
+            (Pressure::Hectopascals(Data::Unknown), PressureUnit::InchesOfMercury) => {
+                Pressure::InchesOfMercury(Data::Unknown)
+            }
+            (pressure, _) => pressure,
+        }
+    }
+}
+
 impl Display for Pressure {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -47,6 +111,26 @@ impl Display for Pressure {
 mod tests {
     use super::*;
 
+    #[test]
+    fn inches_of_mercury() {
+        assert_eq!(
+            Pressure::InchesOfMercury(Data::Known(29.92)).inches_of_mercury(),
+            Some(29.92)
+        );
+        assert!(
+            (Pressure::Hectopascals(Data::Known(1013))
+                .inches_of_mercury()
+                .unwrap()
+                - 29.91)
+                .abs()
+                < 0.01
+        );
+        assert_eq!(
+            Pressure::Hectopascals(Data::Unknown).inches_of_mercury(),
+            None
+        );
+    }
+
     #[test]
     fn valid_hpa() {
         assert_eq!(
@@ -55,12 +139,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn valid_three_digit_hpa() {
+        let pressure = Pressure::parse("Q999").unwrap();
+        assert_eq!(pressure, Pressure::Hectopascals(Data::Known(999)));
+        assert_eq!(pressure.to_string(), "Q0999");
+    }
+
     #[test]
     fn valid_mmhg() {
         assert_eq!(
             Pressure::parse("A3012").unwrap(),
             Pressure::InchesOfMercury(Data::Known(30.12))
         );
+        let pressure = Pressure::parse("A2992").unwrap();
+        assert_eq!(pressure, Pressure::InchesOfMercury(Data::Known(29.92)));
+        assert_eq!(pressure.to_string(), "A2992");
     }
 
     #[test]
@@ -78,4 +172,32 @@ mod tests {
             Pressure::InchesOfMercury(Data::Unknown)
         );
     }
+
+    #[test]
+    fn from_float() {
+        let pressure = Pressure::from_hectopascals(1013.0);
+        assert_eq!(pressure, Pressure::Hectopascals(Data::Known(1013)));
+        assert_eq!(pressure.to_string(), "Q1013");
+        assert_eq!(Pressure::from_hectopascals(998.6).to_string(), "Q0999");
+
+        let pressure = Pressure::from_inches_of_mercury(29.9213);
+        assert_eq!(pressure, Pressure::InchesOfMercury(Data::Known(29.92)));
+        assert_eq!(pressure.to_string(), "A2992");
+    }
+
+    #[test]
+    fn to_unit() {
+        assert_eq!(
+            Pressure::Hectopascals(Data::Known(1013)).to_unit(PressureUnit::InchesOfMercury),
+            Pressure::InchesOfMercury(Data::Known(29.91))
+        );
+        assert_eq!(
+            Pressure::InchesOfMercury(Data::Known(29.92)).to_unit(PressureUnit::Hectopascals),
+            Pressure::Hectopascals(Data::Known(1013))
+        );
+        assert_eq!(
+            Pressure::Hectopascals(Data::Unknown).to_unit(PressureUnit::InchesOfMercury),
+            Pressure::InchesOfMercury(Data::Unknown)
+        );
+    }
 }
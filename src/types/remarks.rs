@@ -0,0 +1,1157 @@
+use std::collections::HashSet;
+
+use chumsky::prelude::*;
+
+use crate::{
+    CloudDensity, CloudLayer, CloudType, CompassDirection, Data, WeatherCondition, WindUnit,
+    parsers::{any_whitespace, some_whitespace},
+    traits::Parsable,
+};
+
+/// Decoded information from the remarks section of a METAR.
+///
+/// Remarks are largely free text, so only the groups listed here are
+/// understood. Anything else is kept, group by group, in [`Remarks::unparsed`].
+#[derive(PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Remarks {
+    /// Temperature and dewpoint to a tenth of a degree (`Txxxxxxxx`)
+    pub precise_temperature: Option<PreciseTemperature>,
+    /// The maximum temperature over the past 6 hours (`1sTTT`)
+    pub temp_max_6hr: Option<f32>,
+    /// The minimum temperature over the past 6 hours (`2sTTT`)
+    pub temp_min_6hr: Option<f32>,
+    /// The maximum and minimum temperatures over the past 24 hours
+    /// (`4sTTTsTTT`)
+    pub temp_extremes_24hr: Option<(f32, f32)>,
+    /// Ice accretion over the past hour in inches (`I1nnn`)
+    pub ice_accretion_1hr_in: Option<Data<f32>>,
+    /// Ice accretion over the past 3 hours in inches (`I3nnn`)
+    pub ice_accretion_3hr_in: Option<Data<f32>>,
+    /// Ice accretion over the past 6 hours in inches (`I6nnn`)
+    pub ice_accretion_6hr_in: Option<Data<f32>>,
+    /// The prevailing visibility varying between two values in statute
+    /// miles, e.g. `VIS 1/2V2`
+    pub variable_visibility: Option<(f32, f32)>,
+    /// The visibility from the control tower in statute miles, e.g.
+    /// `TWR VIS 1 1/2`
+    pub tower_visibility: Option<f32>,
+    /// The visibility at the surface in statute miles, e.g. `SFC VIS 2`
+    pub surface_visibility: Option<f32>,
+    /// Rapid pressure change (`PRESFR` or `PRESRR`)
+    pub pressure_change: Option<PressureChange>,
+    /// The pressure tendency over the past 3 hours (`5appp`)
+    pub pressure_tendency: Option<PressureTendency>,
+    /// A sudden jump in pressure (`PRJMP`), usually with a squall
+    pub pressure_jump: bool,
+    /// The peak wind since the last report (`PK WND`)
+    pub peak_wind: Option<PeakWind>,
+    /// Tornado, waterspout or funnel cloud activity
+    pub tornadic_activity: Option<TornadicActivity>,
+    /// The times weather began or ended, e.g. `RAB15E30` or `REFZRAE55`
+    pub weather_events: Vec<WeatherEvent>,
+    /// Obscurations forming a surface based layer, e.g. `FG SCT000`
+    pub surface_obscurations: Vec<(WeatherCondition, CloudLayer)>,
+    /// Snow increasing rapidly (`SNINCR`), as the inches of snowfall in the
+    /// past hour and the total depth of snow on the ground in inches
+    pub snow_increasing: Option<(u32, u32)>,
+    /// Phenomena observed away from the station, e.g. `CB DSNT NE-E`
+    pub distant_phenomena: Vec<DistantPhenomenon>,
+    /// Standard plain text flags, e.g. `FIRST` or `ACFT MSHP`
+    pub flags: HashSet<RemarkFlag>,
+    /// A front passed the station (`FROPA`)
+    pub frontal_passage: bool,
+    /// Whether the sea level pressure is available: `Some(false)` for
+    /// `SLPNO`, `Some(true)` if a pressure is given, e.g. `SLP142`, and
+    /// [`None`] if it is not mentioned. The pressure itself is left in
+    /// [`Remarks::unparsed`].
+    pub sea_level_pressure_available: Option<bool>,
+    /// The layers of cloud by genus, lowest first, each with its coverage in
+    /// oktas, e.g. `SF2SC5`
+    pub cloud_genus: Vec<(u8, CloudGenus)>,
+    /// Groups which were not recognised
+    pub unparsed: Vec<String>,
+}
+
+/// A single group found in the remarks
+enum RemarkGroup {
+    PreciseTemperature(PreciseTemperature),
+    TempMax6Hr(f32),
+    TempMin6Hr(f32),
+    TempExtremes24Hr((f32, f32)),
+    IceAccretion((u8, Data<f32>)),
+    VariableVisibility((f32, f32)),
+    TowerVisibility(f32),
+    SurfaceVisibility(f32),
+    PressureChange(PressureChange),
+    PressureTendency(PressureTendency),
+    SurfaceObscuration((WeatherCondition, CloudLayer)),
+    PeakWind(PeakWind),
+    TornadicActivity(TornadicActivity),
+    WeatherEvents(Vec<WeatherEvent>),
+    SnowIncreasing((u32, u32)),
+    DistantPhenomenon(DistantPhenomenon),
+    Flag(RemarkFlag),
+    FrontalPassage,
+    PressureJump,
+    SeaLevelPressureNotAvailable,
+    CloudGenus(Vec<(u8, CloudGenus)>),
+    Other(String),
+}
+
+impl Parsable for Remarks {
+    #[allow(clippy::too_many_lines)]
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        let other = none_of(" \t")
+            .repeated()
+            .at_least(1)
+            .to_slice()
+            .map(|s: &str| RemarkGroup::Other(s.to_string()));
+
+        any_whitespace()
+            .ignore_then(
+                choice((
+                    PreciseTemperature::parser()
+                        .map(RemarkGroup::PreciseTemperature)
+                        .then_ignore(some_whitespace()),
+                    temperature_extremes().then_ignore(some_whitespace()),
+                    ice_accretion()
+                        .map(RemarkGroup::IceAccretion)
+                        .then_ignore(some_whitespace()),
+                    variable_visibility()
+                        .map(RemarkGroup::VariableVisibility)
+                        .then_ignore(some_whitespace()),
+                    observer_visibility().then_ignore(some_whitespace()),
+                    PressureChange::parser()
+                        .map(RemarkGroup::PressureChange)
+                        .then_ignore(some_whitespace()),
+                    PressureTendency::parser()
+                        .map(RemarkGroup::PressureTendency)
+                        .then_ignore(some_whitespace()),
+                    surface_obscuration()
+                        .map(RemarkGroup::SurfaceObscuration)
+                        .then_ignore(some_whitespace()),
+                    PeakWind::parser()
+                        .map(RemarkGroup::PeakWind)
+                        .then_ignore(some_whitespace()),
+                    TornadicActivity::parser()
+                        .map(RemarkGroup::TornadicActivity)
+                        .then_ignore(some_whitespace()),
+                    WeatherEvent::parser()
+                        .repeated()
+                        .at_least(1)
+                        .collect::<Vec<_>>()
+                        .map(RemarkGroup::WeatherEvents)
+                        .then_ignore(some_whitespace()),
+                    snow_increasing()
+                        .map(RemarkGroup::SnowIncreasing)
+                        .then_ignore(some_whitespace()),
+                    cloud_genus_layers()
+                        .map(RemarkGroup::CloudGenus)
+                        .then_ignore(some_whitespace()),
+                    DistantPhenomenon::parser()
+                        .map(RemarkGroup::DistantPhenomenon)
+                        .then_ignore(some_whitespace()),
+                    RemarkFlag::parser()
+                        .map(RemarkGroup::Flag)
+                        .then_ignore(some_whitespace()),
+                    just("FROPA")
+                        .map(|_| RemarkGroup::FrontalPassage)
+                        .then_ignore(some_whitespace()),
+                    just("PRJMP")
+                        .map(|_| RemarkGroup::PressureJump)
+                        .then_ignore(some_whitespace()),
+                    just("SLPNO")
+                        .map(|_| RemarkGroup::SeaLevelPressureNotAvailable)
+                        .then_ignore(some_whitespace()),
+                    other.then_ignore(any_whitespace()),
+                ))
+                .repeated()
+                .collect::<Vec<_>>(),
+            )
+            .map(|groups| {
+                let mut remarks = Remarks::default();
+                for group in groups {
+                    match group {
+                        RemarkGroup::PreciseTemperature(t) => {
+                            remarks.precise_temperature = Some(t);
+                        }
+                        RemarkGroup::TempMax6Hr(t) => remarks.temp_max_6hr = Some(t),
+                        RemarkGroup::TempMin6Hr(t) => remarks.temp_min_6hr = Some(t),
+                        RemarkGroup::TempExtremes24Hr(t) => remarks.temp_extremes_24hr = Some(t),
+                        RemarkGroup::IceAccretion((hours, ice)) => match hours {
+                            1 => remarks.ice_accretion_1hr_in = Some(ice),
+                            3 => remarks.ice_accretion_3hr_in = Some(ice),
+                            _ => remarks.ice_accretion_6hr_in = Some(ice),
+                        },
+                        RemarkGroup::VariableVisibility(v) => remarks.variable_visibility = Some(v),
+                        RemarkGroup::TowerVisibility(v) => remarks.tower_visibility = Some(v),
+                        RemarkGroup::SurfaceVisibility(v) => remarks.surface_visibility = Some(v),
+                        RemarkGroup::PressureChange(c) => remarks.pressure_change = Some(c),
+                        RemarkGroup::PressureTendency(t) => remarks.pressure_tendency = Some(t),
+                        RemarkGroup::SurfaceObscuration(o) => remarks.surface_obscurations.push(o),
+                        RemarkGroup::PeakWind(w) => remarks.peak_wind = Some(w),
+                        RemarkGroup::TornadicActivity(t) => remarks.tornadic_activity = Some(t),
+                        RemarkGroup::WeatherEvents(e) => remarks.weather_events.extend(e),
+                        RemarkGroup::SnowIncreasing(s) => remarks.snow_increasing = Some(s),
+                        RemarkGroup::DistantPhenomenon(p) => remarks.distant_phenomena.push(p),
+                        RemarkGroup::Flag(f) => {
+                            remarks.flags.insert(f);
+                        }
+                        RemarkGroup::FrontalPassage => remarks.frontal_passage = true,
+                        RemarkGroup::PressureJump => remarks.pressure_jump = true,
+                        RemarkGroup::CloudGenus(layers) => remarks.cloud_genus.extend(layers),
+                        RemarkGroup::SeaLevelPressureNotAvailable => {
+                            remarks.sea_level_pressure_available = Some(false);
+                        }
+                        RemarkGroup::Other(s) => {
+                            if s.strip_prefix("SLP").is_some_and(|p| {
+                                p.len() == 3 && p.bytes().all(|b| b.is_ascii_digit())
+                            }) {
+                                remarks.sea_level_pressure_available = Some(true);
+                            }
+                            remarks.unparsed.push(s);
+                        }
+                    }
+                }
+                remarks
+            })
+    }
+}
+
+/// Temperature and dewpoint reported to a tenth of a degree Celsius
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PreciseTemperature {
+    /// The temperature
+    pub temperature: f32,
+    /// The dewpoint, if given
+    pub dewpoint: Option<f32>,
+}
+
+/// Parse a signed temperature in tenths of a degree, e.g. `1028` is -2.8
+fn tenths_temperature<'src>()
+-> impl Parser<'src, &'src str, f32, extra::Err<crate::MetarError<'src>>> {
+    group((
+        one_of("01"),
+        text::digits(10)
+            .exactly(3)
+            .to_slice()
+            .map(|d: &str| f32::from(d.parse::<u16>().unwrap()) / 10.),
+    ))
+    .map(|(sign, v)| if sign == '1' { -v } else { v })
+}
+
+/// Parse the 6 hour maximum (`1sTTT`) or minimum (`2sTTT`) temperature, or
+/// the 24 hour maximum and minimum temperatures (`4sTTTsTTT`)
+fn temperature_extremes<'src>()
+-> impl Parser<'src, &'src str, RemarkGroup, extra::Err<crate::MetarError<'src>>> {
+    choice((
+        just("1")
+            .ignore_then(tenths_temperature())
+            .map(RemarkGroup::TempMax6Hr),
+        just("2")
+            .ignore_then(tenths_temperature())
+            .map(RemarkGroup::TempMin6Hr),
+        just("4")
+            .ignore_then(tenths_temperature().then(tenths_temperature()))
+            .map(RemarkGroup::TempExtremes24Hr),
+    ))
+}
+
+/// Parse the ice accretion over 1, 3 or 6 hours in hundredths of an inch,
+/// e.g. `I1002`, as the number of hours and the accretion in inches
+fn ice_accretion<'src>()
+-> impl Parser<'src, &'src str, (u8, Data<f32>), extra::Err<crate::MetarError<'src>>> {
+    group((
+        just("I"),
+        one_of("136").to_slice().map(|h: &str| h.parse().unwrap()),
+        choice((
+            just("/")
+                .repeated()
+                .at_least(3)
+                .at_most(4)
+                .map(|()| Data::Unknown),
+            text::digits(10)
+                .exactly(3)
+                .to_slice()
+                .map(|d: &str| Data::Known(f32::from(d.parse::<u16>().unwrap()) / 100.)),
+        )),
+    ))
+    .map(|(_, hours, ice)| (hours, ice))
+}
+
+/// Parse a visibility in statute miles without a unit, e.g. `2`, `3/4` or
+/// `1 1/2`
+fn statute_miles<'src>() -> impl Parser<'src, &'src str, f32, extra::Err<crate::MetarError<'src>>> {
+    let whole = || {
+        text::digits(10)
+            .at_least(1)
+            .at_most(2)
+            .to_slice()
+            .map(|d: &str| f32::from(d.parse::<u8>().unwrap()))
+    };
+    let fraction = || {
+        group((
+            text::digits(10).exactly(1).to_slice(),
+            just("/"),
+            text::digits(10).exactly(1).to_slice(),
+        ))
+        .map(|(numerator, _, denominator): (&str, &str, &str)| {
+            let numerator: f32 = numerator.parse().unwrap();
+            let denominator: f32 = denominator.parse().unwrap();
+            numerator / denominator
+        })
+    };
+
+    choice((
+        group((whole(), text::inline_whitespace().at_least(1), fraction()))
+            .map(|(whole, (), fraction)| whole + fraction),
+        fraction(),
+        whole(),
+    ))
+}
+
+/// Parse the prevailing visibility varying between two values, e.g.
+/// `VIS 3/4V1 1/2`
+fn variable_visibility<'src>()
+-> impl Parser<'src, &'src str, (f32, f32), extra::Err<crate::MetarError<'src>>> {
+    group((
+        just("VIS"),
+        text::inline_whitespace().at_least(1),
+        statute_miles(),
+        just("V"),
+        statute_miles(),
+    ))
+    .map(|(_, (), min, _, max)| (min, max))
+}
+
+/// Parse the visibility from the control tower or at the surface, e.g.
+/// `TWR VIS 1 1/2` or `SFC VIS 2`
+fn observer_visibility<'src>()
+-> impl Parser<'src, &'src str, RemarkGroup, extra::Err<crate::MetarError<'src>>> {
+    let whitespace = || text::inline_whitespace().at_least(1);
+    let visibility =
+        || group((whitespace(), just("VIS"), whitespace())).ignore_then(statute_miles());
+
+    choice((
+        just("TWR")
+            .ignore_then(visibility())
+            .map(RemarkGroup::TowerVisibility),
+        just("SFC")
+            .ignore_then(visibility())
+            .map(RemarkGroup::SurfaceVisibility),
+    ))
+}
+
+impl Parsable for PreciseTemperature {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        group((
+            just("T"),
+            tenths_temperature(),
+            tenths_temperature().map(Some).or(empty().map(|()| None)),
+        ))
+        .map(|(_, temperature, dewpoint)| PreciseTemperature {
+            temperature,
+            dewpoint,
+        })
+    }
+}
+
+/// A rapid change in pressure
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PressureChange {
+    /// Pressure rising rapidly (`PRESRR`)
+    Rising,
+    /// Pressure falling rapidly (`PRESFR`)
+    Falling,
+}
+
+impl Parsable for PressureChange {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("PRESRR").map(|_| PressureChange::Rising),
+            just("PRESFR").map(|_| PressureChange::Falling),
+        ))
+    }
+}
+
+/// The pressure tendency over the past 3 hours, e.g. `52019`
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureTendency {
+    /// The WMO code for how the pressure changed, from 0 to 8. 0 to 3 mean
+    /// the pressure is higher than 3 hours ago, 4 that it is the same, and 5
+    /// to 8 that it is lower.
+    pub characteristic: u8,
+    /// The change in pressure in hectopascals, negative if it fell
+    pub change_hpa: f32,
+}
+
+impl Parsable for PressureTendency {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        group((
+            just("5"),
+            one_of("012345678")
+                .to_slice()
+                .map(|d: &str| d.parse::<u8>().unwrap()),
+            text::digits(10)
+                .exactly(3)
+                .to_slice()
+                .map(|d: &str| f32::from(d.parse::<u16>().unwrap()) / 10.),
+        ))
+        .map(|(_, characteristic, change)| PressureTendency {
+            characteristic,
+            change_hpa: if characteristic >= 5 { -change } else { change },
+        })
+    }
+}
+
+/// A standard plain text remark
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RemarkFlag {
+    /// The report was prompted by an aircraft mishap (`ACFT MSHP`)
+    AircraftMishap,
+    /// The first report after the station opened (`FIRST`)
+    First,
+    /// The last report before the station closes (`LAST`)
+    Last,
+    /// Frost was found on the ice accretion indicator (`FROIN`)
+    FrostOnIndicator,
+}
+
+impl Parsable for RemarkFlag {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            group((
+                just("ACFT"),
+                text::inline_whitespace().at_least(1),
+                just("MSHP"),
+            ))
+            .map(|_| RemarkFlag::AircraftMishap),
+            just("FIRST").map(|_| RemarkFlag::First),
+            just("LAST").map(|_| RemarkFlag::Last),
+            just("FROIN").map(|_| RemarkFlag::FrostOnIndicator),
+        ))
+    }
+}
+
+/// The peak wind since the last report, e.g. `PK WND 28045/1523`
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeakWind {
+    /// The direction of the peak wind, in degrees
+    pub dir: u32,
+    /// The speed of the peak wind
+    pub speed: u32,
+    /// The unit of [`PeakWind::speed`]. The remark itself carries no unit, so
+    /// this follows the wind in the body of the report.
+    pub unit: WindUnit,
+    /// The time the peak wind occurred, either as `HHMM` or, if within the
+    /// hour of the report, just `MM`
+    pub time: u16,
+}
+
+impl Parsable for PeakWind {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        group((
+            just("PK"),
+            text::inline_whitespace().at_least(1),
+            just("WND"),
+            text::inline_whitespace().at_least(1),
+            text::digits(10).exactly(3).to_slice(),
+            text::digits(10).at_least(2).at_most(3).to_slice(),
+            just("/"),
+            choice((
+                text::digits(10).exactly(4).to_slice(),
+                text::digits(10).exactly(2).to_slice(),
+            )),
+        ))
+        .map(
+            |(_, (), _, (), dir, speed, _, time): (_, _, _, _, &str, &str, _, &str)| PeakWind {
+                dir: dir.parse().unwrap(),
+                speed: speed.parse().unwrap(),
+                unit: WindUnit::Knots,
+                time: time.parse().unwrap(),
+            },
+        )
+    }
+}
+
+/// Tornadic activity, e.g. `TORNADO B13 6 NE`
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TornadicActivity {
+    /// The kind of activity
+    pub kind: TornadicActivityKind,
+    /// The time the activity began, either as `HHMM` or just `MM`
+    pub begin: Option<u16>,
+    /// The time the activity ended, either as `HHMM` or just `MM`
+    pub end: Option<u16>,
+    /// The words describing the location and movement, e.g. `["DSNT", "NE"]`
+    pub location: Vec<String>,
+}
+
+/// Parse the time an event began or ended, either as `HHMM` or just `MM`
+fn event_time<'src>() -> impl Parser<'src, &'src str, u16, extra::Err<crate::MetarError<'src>>> {
+    choice((
+        text::digits(10).exactly(4).to_slice(),
+        text::digits(10).exactly(2).to_slice(),
+    ))
+    .map(|d: &str| d.parse::<u16>().unwrap())
+}
+
+impl Parsable for TornadicActivity {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        let whitespace = text::inline_whitespace().at_least(1);
+        let location_word = choice((
+            just("DSNT").to_slice(),
+            just("VC").to_slice(),
+            just("OHD").to_slice(),
+            just("MOV").to_slice(),
+            text::digits(10).at_least(1).to_slice(),
+            group((
+                CompassDirection::parser(),
+                just("-").then(CompassDirection::parser()).or_not(),
+            ))
+            .to_slice(),
+        ))
+        .then_ignore(some_whitespace().rewind())
+        .map(ToString::to_string);
+
+        group((
+            TornadicActivityKind::parser(),
+            whitespace
+                .ignore_then(
+                    just("B")
+                        .ignore_then(event_time())
+                        .or_not()
+                        .then(just("E").ignore_then(event_time()).or_not())
+                        .filter(|(begin, end)| begin.is_some() || end.is_some()),
+                )
+                .or_not(),
+            whitespace
+                .ignore_then(location_word)
+                .repeated()
+                .collect::<Vec<_>>(),
+        ))
+        .map(|(kind, times, location)| {
+            let (begin, end) = times.unwrap_or_default();
+            TornadicActivity {
+                kind,
+                begin,
+                end,
+                location,
+            }
+        })
+    }
+}
+
+/// The time weather began or ended, e.g. `RAB15E30`.
+///
+/// Recent weather may be given with a leading `RE`, e.g. `REFZRAE55`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeatherEvent {
+    /// The weather conditions, e.g. `[Freezing, Rain]`
+    pub conditions: Vec<WeatherCondition>,
+    /// The time the weather began, either as `HHMM` or just `MM`
+    pub begin: Option<u16>,
+    /// The time the weather ended, either as `HHMM` or just `MM`
+    pub end: Option<u16>,
+}
+
+impl Parsable for WeatherEvent {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        group((
+            just("RE").or_not(),
+            WeatherCondition::parser()
+                .repeated()
+                .at_least(1)
+                .collect::<Vec<_>>(),
+            just("B").ignore_then(event_time()).or_not(),
+            just("E").ignore_then(event_time()).or_not(),
+        ))
+        .filter(|(_, _, begin, end)| begin.is_some() || end.is_some())
+        .map(|(_, conditions, begin, end)| WeatherEvent {
+            conditions,
+            begin,
+            end,
+        })
+    }
+}
+
+/// The kind of tornadic activity
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TornadicActivityKind {
+    /// Tornado (`TORNADO`)
+    Tornado,
+    /// Waterspout (`WATERSPOUT`)
+    Waterspout,
+    /// Funnel cloud (`FUNNEL CLOUD`)
+    FunnelCloud,
+}
+
+impl Parsable for TornadicActivityKind {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("TORNADO").map(|_| TornadicActivityKind::Tornado),
+            just("WATERSPOUT").map(|_| TornadicActivityKind::Waterspout),
+            group((
+                just("FUNNEL"),
+                text::inline_whitespace().at_least(1),
+                just("CLOUD"),
+            ))
+            .map(|_| TornadicActivityKind::FunnelCloud),
+        ))
+    }
+}
+
+/// Parse an obscuration with a surface based layer, e.g. `FG SCT000`.
+///
+/// Only layers based at the surface (`000`) are obscurations; the same
+/// pattern with a height describes cloud and is left undecoded.
+fn surface_obscuration<'src>()
+-> impl Parser<'src, &'src str, (WeatherCondition, CloudLayer), extra::Err<crate::MetarError<'src>>>
+{
+    group((
+        WeatherCondition::parser(),
+        text::inline_whitespace().at_least(1),
+        CloudDensity::parser(),
+        just("000"),
+    ))
+    .map(|(condition, (), density, _)| {
+        (
+            condition,
+            CloudLayer {
+                density: Data::Known(density),
+                kind: Data::Known(CloudType::Normal),
+                height: Data::Known(0),
+            },
+        )
+    })
+}
+
+/// A phenomenon observed away from the station, e.g. `VIRGA SW` or
+/// `CB DSNT NE-E`
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistantPhenomenon {
+    /// The phenomenon observed, e.g. `CB`, `VIRGA` or `ACSL`
+    pub kind: String,
+    /// Whether the phenomenon is distant (`DSNT`) from the station
+    pub distant: bool,
+    /// The directions the phenomenon was observed in. A range such as `NE-E`
+    /// is given as its two ends.
+    pub bearings: Vec<CompassDirection>,
+}
+
+impl Parsable for DistantPhenomenon {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        let whitespace = text::inline_whitespace().at_least(1);
+
+        group((
+            choice((
+                just("VIRGA"),
+                just("CBMAM"),
+                just("CB"),
+                just("TCU"),
+                just("ACSL"),
+                just("SCSL"),
+                just("CCSL"),
+                just("ACC"),
+            )),
+            whitespace.ignore_then(just("DSNT")).or_not(),
+            whitespace
+                .ignore_then(
+                    CompassDirection::parser()
+                        .separated_by(just("-"))
+                        .at_least(1)
+                        .collect::<Vec<_>>(),
+                )
+                .then_ignore(some_whitespace().rewind())
+                .or_not(),
+        ))
+        .then_ignore(some_whitespace().rewind())
+        .map(|(kind, distant, bearings)| DistantPhenomenon {
+            kind: kind.to_string(),
+            distant: distant.is_some(),
+            bearings: bearings.unwrap_or_default(),
+        })
+    }
+}
+
+/// A genus of cloud, as reported in remarks by some countries
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudGenus {
+    /// Cirrus (`CI`)
+    Cirrus,
+    /// Cirrostratus (`CS`)
+    Cirrostratus,
+    /// Cirrocumulus (`CC`)
+    Cirrocumulus,
+    /// Altostratus (`AS`)
+    Altostratus,
+    /// Altocumulus (`AC`)
+    Altocumulus,
+    /// Nimbostratus (`NS`)
+    Nimbostratus,
+    /// Stratocumulus (`SC`)
+    Stratocumulus,
+    /// Stratus (`ST`)
+    Stratus,
+    /// Stratus fractus (`SF`)
+    StratusFractus,
+    /// Cumulus (`CU`)
+    Cumulus,
+    /// Cumulus fractus (`CF`)
+    CumulusFractus,
+    /// Towering cumulus (`TCU`)
+    ToweringCumulus,
+    /// Cumulonimbus (`CB`)
+    Cumulonimbus,
+    /// Fog, reported in place of a cloud layer (`FG`)
+    Fog,
+}
+
+impl Parsable for CloudGenus {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("CI").map(|_| CloudGenus::Cirrus),
+            just("CS").map(|_| CloudGenus::Cirrostratus),
+            just("CC").map(|_| CloudGenus::Cirrocumulus),
+            just("AS").map(|_| CloudGenus::Altostratus),
+            just("AC").map(|_| CloudGenus::Altocumulus),
+            just("NS").map(|_| CloudGenus::Nimbostratus),
+            just("SC").map(|_| CloudGenus::Stratocumulus),
+            just("ST").map(|_| CloudGenus::Stratus),
+            just("SF").map(|_| CloudGenus::StratusFractus),
+            just("CU").map(|_| CloudGenus::Cumulus),
+            just("CF").map(|_| CloudGenus::CumulusFractus),
+            just("TCU").map(|_| CloudGenus::ToweringCumulus),
+            just("CB").map(|_| CloudGenus::Cumulonimbus),
+            just("FG").map(|_| CloudGenus::Fog),
+        ))
+    }
+}
+
+/// Parse the layers of cloud by genus with their coverage in oktas, e.g.
+/// `SF2SC5`
+fn cloud_genus_layers<'src>()
+-> impl Parser<'src, &'src str, Vec<(u8, CloudGenus)>, extra::Err<crate::MetarError<'src>>> {
+    group((
+        CloudGenus::parser(),
+        text::digits(10)
+            .exactly(1)
+            .to_slice()
+            .map(|d: &str| d.parse().unwrap()),
+    ))
+    .map(|(genus, oktas)| (oktas, genus))
+    .repeated()
+    .at_least(1)
+    .collect::<Vec<_>>()
+}
+
+/// Parse snow increasing rapidly, e.g. `SNINCR 2/10`
+fn snow_increasing<'src>()
+-> impl Parser<'src, &'src str, (u32, u32), extra::Err<crate::MetarError<'src>>> {
+    let inches = || {
+        text::digits(10)
+            .at_least(1)
+            .at_most(3)
+            .to_slice()
+            .map(|d: &str| d.parse().unwrap())
+    };
+    group((
+        just("SNINCR"),
+        text::inline_whitespace().at_least(1),
+        inches(),
+        just("/"),
+        inches(),
+    ))
+    .map(|(_, (), last_hour, _, total)| (last_hour, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precise_temperature() {
+        assert_eq!(
+            PreciseTemperature::parse("T02830228").unwrap(),
+            PreciseTemperature {
+                temperature: 28.3,
+                dewpoint: Some(22.8),
+            }
+        );
+        assert_eq!(
+            PreciseTemperature::parse("T00611028").unwrap(),
+            PreciseTemperature {
+                temperature: 6.1,
+                dewpoint: Some(-2.8),
+            }
+        );
+    }
+
+    #[test]
+    fn test_temperature_extremes() {
+        let remarks = Remarks::parse("AO2 SLP142 10142 21067 401121084").unwrap();
+        assert_eq!(remarks.temp_max_6hr, Some(14.2));
+        assert_eq!(remarks.temp_min_6hr, Some(-6.7));
+        assert_eq!(remarks.temp_extremes_24hr, Some((11.2, -8.4)));
+        assert!(remarks.unparsed.iter().all(|g| g == "AO2" || g == "SLP142"));
+    }
+
+    #[test]
+    fn test_ice_accretion() {
+        let remarks = Remarks::parse("AO2 FZRA I1002 I3005 I6////").unwrap();
+        assert_eq!(remarks.ice_accretion_1hr_in, Some(Data::Known(0.02)));
+        assert_eq!(remarks.ice_accretion_3hr_in, Some(Data::Known(0.05)));
+        assert_eq!(remarks.ice_accretion_6hr_in, Some(Data::Unknown));
+
+        let remarks = Remarks::parse("AO2 I1////").unwrap();
+        assert_eq!(remarks.ice_accretion_1hr_in, Some(Data::Unknown));
+        assert_eq!(remarks.ice_accretion_3hr_in, None);
+    }
+
+    #[test]
+    fn test_variable_visibility() {
+        assert_eq!(
+            Remarks::parse("AO2 VIS 3/4V1 1/2 SLP142")
+                .unwrap()
+                .variable_visibility,
+            Some((0.75, 1.5))
+        );
+        assert_eq!(
+            Remarks::parse("AO2 VIS 1/2V2").unwrap().variable_visibility,
+            Some((0.5, 2.))
+        );
+        assert_eq!(
+            Remarks::parse("AO2 VIS 2 RWY11")
+                .unwrap()
+                .variable_visibility,
+            None
+        );
+    }
+
+    #[test]
+    fn test_pressure_tendency() {
+        let remarks = Remarks::parse("PRESRR 52019").unwrap();
+        assert_eq!(remarks.pressure_change, Some(PressureChange::Rising));
+        assert_eq!(
+            remarks.pressure_tendency,
+            Some(PressureTendency {
+                characteristic: 2,
+                change_hpa: 1.9,
+            })
+        );
+
+        let remarks = Remarks::parse("AO2 57012 PRESFR").unwrap();
+        assert_eq!(remarks.pressure_change, Some(PressureChange::Falling));
+        assert_eq!(
+            remarks.pressure_tendency,
+            Some(PressureTendency {
+                characteristic: 7,
+                change_hpa: -1.2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_observer_visibility() {
+        let remarks = Remarks::parse("AO2 TWR VIS 1 1/2 SLP142").unwrap();
+        assert_eq!(remarks.tower_visibility, Some(1.5));
+        assert_eq!(remarks.surface_visibility, None);
+
+        let remarks = Remarks::parse("AO2 SFC VIS 2 TWR VIS 3/4").unwrap();
+        assert_eq!(remarks.surface_visibility, Some(2.));
+        assert_eq!(remarks.tower_visibility, Some(0.75));
+    }
+
+    #[test]
+    fn test_remarks() {
+        assert_eq!(
+            Remarks::parse("AO2 SLP142 T02830228").unwrap(),
+            Remarks {
+                precise_temperature: Some(PreciseTemperature {
+                    temperature: 28.3,
+                    dewpoint: Some(22.8),
+                }),
+                sea_level_pressure_available: Some(true),
+                unparsed: vec!["AO2".to_string(), "SLP142".to_string()],
+                ..Remarks::default()
+            }
+        );
+        assert_eq!(
+            Remarks::parse("TEMPORARY").unwrap(),
+            Remarks {
+                unparsed: vec!["TEMPORARY".to_string()],
+                ..Remarks::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_sea_level_pressure_available() {
+        let remarks = Remarks::parse("AO2 SLPNO").unwrap();
+        assert_eq!(remarks.sea_level_pressure_available, Some(false));
+        assert_eq!(remarks.unparsed, vec!["AO2".to_string()]);
+
+        assert_eq!(
+            Remarks::parse("AO2 SLP142")
+                .unwrap()
+                .sea_level_pressure_available,
+            Some(true)
+        );
+        assert_eq!(
+            Remarks::parse("AO2").unwrap().sea_level_pressure_available,
+            None
+        );
+    }
+
+    #[test]
+    fn test_pressure_change() {
+        assert_eq!(
+            Remarks::parse("PRESFR").unwrap().pressure_change,
+            Some(PressureChange::Falling)
+        );
+        assert_eq!(
+            Remarks::parse("AO2 PRESRR").unwrap().pressure_change,
+            Some(PressureChange::Rising)
+        );
+    }
+
+    #[test]
+    fn test_surface_obscuration() {
+        assert_eq!(
+            Remarks::parse("FG SCT000").unwrap().surface_obscurations,
+            vec![(
+                WeatherCondition::Fog,
+                CloudLayer {
+                    density: Data::Known(CloudDensity::Scattered),
+                    kind: Data::Known(CloudType::Normal),
+                    height: Data::Known(0),
+                }
+            )]
+        );
+        assert!(
+            Remarks::parse("FU BKN020")
+                .unwrap()
+                .surface_obscurations
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_peak_wind() {
+        assert_eq!(
+            Remarks::parse("AO2 PK WND 30048/35").unwrap().peak_wind,
+            Some(PeakWind {
+                dir: 300,
+                speed: 48,
+                unit: WindUnit::Knots,
+                time: 35,
+            })
+        );
+        assert_eq!(
+            Remarks::parse("PK WND 280105/1523").unwrap().peak_wind,
+            Some(PeakWind {
+                dir: 280,
+                speed: 105,
+                unit: WindUnit::Knots,
+                time: 1523,
+            })
+        );
+    }
+
+    #[test]
+    fn test_squall_remarks() {
+        let peak_wind = Some(PeakWind {
+            dir: 270,
+            speed: 50,
+            unit: WindUnit::Knots,
+            time: 15,
+        });
+        assert_eq!(
+            Remarks::parse("PK WND 27050/15 PRESRR").unwrap(),
+            Remarks {
+                peak_wind,
+                pressure_change: Some(PressureChange::Rising),
+                ..Remarks::default()
+            }
+        );
+        assert_eq!(
+            Remarks::parse("AO2 PK WND 27050/15 PRJMP PRESRR").unwrap(),
+            Remarks {
+                peak_wind,
+                pressure_change: Some(PressureChange::Rising),
+                pressure_jump: true,
+                unparsed: vec!["AO2".to_string()],
+                ..Remarks::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_tornadic_activity() {
+        assert_eq!(
+            Remarks::parse("AO2 FUNNEL CLOUD B25 S SLP142")
+                .unwrap()
+                .tornadic_activity,
+            Some(TornadicActivity {
+                kind: TornadicActivityKind::FunnelCloud,
+                begin: Some(25),
+                end: None,
+                location: vec!["S".to_string()],
+            })
+        );
+        assert_eq!(
+            Remarks::parse("TORNADO B1312E1345 DSNT NE MOV E")
+                .unwrap()
+                .tornadic_activity,
+            Some(TornadicActivity {
+                kind: TornadicActivityKind::Tornado,
+                begin: Some(1312),
+                end: Some(1345),
+                location: vec![
+                    "DSNT".to_string(),
+                    "NE".to_string(),
+                    "MOV".to_string(),
+                    "E".to_string(),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_weather_events() {
+        assert_eq!(
+            Remarks::parse("AO2 REFZRAE55 SLP142")
+                .unwrap()
+                .weather_events,
+            vec![WeatherEvent {
+                conditions: vec![WeatherCondition::Freezing, WeatherCondition::Rain],
+                begin: None,
+                end: Some(55),
+            }]
+        );
+        assert_eq!(
+            Remarks::parse("AO2 RAB15E30SNB1230")
+                .unwrap()
+                .weather_events,
+            vec![
+                WeatherEvent {
+                    conditions: vec![WeatherCondition::Rain],
+                    begin: Some(15),
+                    end: Some(30),
+                },
+                WeatherEvent {
+                    conditions: vec![WeatherCondition::Snow],
+                    begin: Some(1230),
+                    end: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snow_increasing() {
+        assert_eq!(
+            Remarks::parse("AO2 SNINCR 3/14 SLP142")
+                .unwrap()
+                .snow_increasing,
+            Some((3, 14))
+        );
+    }
+
+    #[test]
+    fn test_distant_phenomena() {
+        assert_eq!(
+            Remarks::parse("AO2 CB DSNT NE-E VIRGA SW")
+                .unwrap()
+                .distant_phenomena,
+            vec![
+                DistantPhenomenon {
+                    kind: "CB".to_string(),
+                    distant: true,
+                    bearings: vec![CompassDirection::NorthEast, CompassDirection::East],
+                },
+                DistantPhenomenon {
+                    kind: "VIRGA".to_string(),
+                    distant: false,
+                    bearings: vec![CompassDirection::SouthWest],
+                },
+            ]
+        );
+        assert!(
+            Remarks::parse("CBX NE")
+                .unwrap()
+                .distant_phenomena
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(
+            Remarks::parse("AO2 FIRST").unwrap(),
+            Remarks {
+                flags: HashSet::from([RemarkFlag::First]),
+                unparsed: vec!["AO2".to_string()],
+                ..Remarks::default()
+            }
+        );
+        assert_eq!(
+            Remarks::parse("ACFT MSHP LASTING").unwrap(),
+            Remarks {
+                flags: HashSet::from([RemarkFlag::AircraftMishap]),
+                unparsed: vec!["LASTING".to_string()],
+                ..Remarks::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_cloud_genus() {
+        assert_eq!(
+            Remarks::parse("SC1SC5 SLP101").unwrap(),
+            Remarks {
+                cloud_genus: vec![
+                    (1, CloudGenus::Stratocumulus),
+                    (5, CloudGenus::Stratocumulus)
+                ],
+                sea_level_pressure_available: Some(true),
+                unparsed: vec!["SLP101".to_string()],
+                ..Remarks::default()
+            }
+        );
+        assert_eq!(
+            Remarks::parse("FG2SF3TCU1").unwrap().cloud_genus,
+            vec![
+                (2, CloudGenus::Fog),
+                (3, CloudGenus::StratusFractus),
+                (1, CloudGenus::ToweringCumulus)
+            ]
+        );
+        assert!(Remarks::parse("SC1X").unwrap().cloud_genus.is_empty());
+    }
+
+    #[test]
+    fn test_frontal_passage() {
+        assert_eq!(
+            Remarks::parse("AO2 PRESRR FROPA").unwrap(),
+            Remarks {
+                pressure_change: Some(PressureChange::Rising),
+                frontal_passage: true,
+                unparsed: vec!["AO2".to_string()],
+                ..Remarks::default()
+            }
+        );
+        assert!(!Remarks::parse("AO2 FROPAX").unwrap().frontal_passage);
+    }
+}
@@ -1,6 +1,6 @@
 use chumsky::prelude::*;
 
-use crate::{Data, parsers::runway_number, traits::Parsable};
+use crate::{Data, ErrorVariant, parsers::runway_number, traits::Parsable};
 
 /// Describes contamination on a runway
 #[derive(PartialEq, Clone, Debug)]
@@ -10,8 +10,8 @@ pub struct RunwayCondition {
     pub runway_number: String,
     /// Contamination detail
     pub contamination: RunwayContamination,
-    /// Percentage of braking action on the runway
-    pub braking_action: Data<u8>,
+    /// The friction or braking action on the runway
+    pub friction: RunwayFriction,
 }
 
 impl Parsable for RunwayCondition {
@@ -20,24 +20,49 @@ impl Parsable for RunwayCondition {
             runway_number(),
             just("/"),
             RunwayContamination::parser(),
-            Data::parser_inline(
-                2,
-                text::digits(10)
-                    .exactly(2)
-                    .to_slice()
-                    .map(|d: &str| d.parse().unwrap()),
-            ),
+            RunwayFriction::parser(),
         ))
         .map(
-            |(runway_number, _, contamination, braking_action)| RunwayCondition {
+            |(runway_number, _, contamination, friction)| RunwayCondition {
                 runway_number,
                 contamination,
-                braking_action,
+                friction,
             },
         )
     }
 }
 
+/// The friction on a runway, e.g. `91`
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunwayFriction {
+    /// The measured friction coefficient, from `00` to `90`, e.g. `0.45`
+    Coefficient(f32),
+    /// The estimated braking action, from 1 (poor, `91`) to 5 (good, `95`)
+    BrakingAction(u8),
+    /// The measurement is unreliable or unavailable (`99`)
+    Unreliable,
+    /// The friction is not reported (`//`)
+    Unknown,
+}
+
+impl Parsable for RunwayFriction {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice((
+            just("//").map(|_| RunwayFriction::Unknown),
+            text::digits(10)
+                .exactly(2)
+                .to_slice()
+                .try_map(|d: &str, span| match d.parse::<u8>().unwrap() {
+                    code @ 0..=90 => Ok(RunwayFriction::Coefficient(f32::from(code) / 100.)),
+                    code @ 91..=95 => Ok(RunwayFriction::BrakingAction(code - 90)),
+                    99 => Ok(RunwayFriction::Unreliable),
+                    _ => Err(ErrorVariant::InvalidRunwayFriction.into_err(span)),
+                }),
+        ))
+    }
+}
+
 /// Describes contamination on a runway
 #[derive(PartialEq, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -121,3 +146,49 @@ impl Parsable for RunwayDeposits {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_friction() {
+        assert_eq!(
+            RunwayCondition::parse("R24/290291").unwrap().friction,
+            RunwayFriction::BrakingAction(1)
+        );
+        assert_eq!(
+            RunwayCondition::parse("R24/290299").unwrap().friction,
+            RunwayFriction::Unreliable
+        );
+        assert_eq!(
+            RunwayCondition::parse("R24/290245").unwrap().friction,
+            RunwayFriction::Coefficient(0.45)
+        );
+        assert_eq!(
+            RunwayCondition::parse("R24/2902//").unwrap().friction,
+            RunwayFriction::Unknown
+        );
+        assert!(RunwayCondition::parse("R24/290297").is_err());
+    }
+
+    #[test]
+    fn test_cleared() {
+        assert_eq!(
+            RunwayCondition::parse("R88/CLRD//").unwrap(),
+            RunwayCondition {
+                runway_number: "88".to_string(),
+                contamination: RunwayContamination::Cleared,
+                friction: RunwayFriction::Unknown,
+            }
+        );
+        assert_eq!(
+            RunwayCondition::parse("R24/CLRD70").unwrap(),
+            RunwayCondition {
+                runway_number: "24".to_string(),
+                contamination: RunwayContamination::Cleared,
+                friction: RunwayFriction::Coefficient(0.7),
+            }
+        );
+    }
+}
@@ -233,4 +233,55 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_rvr_not_available() {
+        assert_eq!(
+            RunwayVisualRange::parse("R24L/////FT").unwrap(),
+            RunwayVisualRange {
+                runway: "24L".to_string(),
+                value: Data::Unknown,
+                unit: RvrUnit::Feet,
+                trend: Data::Known(RvrTrend::None),
+            }
+        );
+        assert_eq!(
+            RunwayVisualRange::parse("R24/////").unwrap(),
+            RunwayVisualRange {
+                runway: "24".to_string(),
+                value: Data::Unknown,
+                unit: RvrUnit::Metres,
+                trend: Data::Known(RvrTrend::None),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rvr_varying_with_trend() {
+        let rvr = RunwayVisualRange::parse("R06/0550V0800U").unwrap();
+        assert_eq!(
+            rvr,
+            RunwayVisualRange {
+                runway: "06".to_string(),
+                value: Data::Known(RvrValue::Between(
+                    RvrValueInner::Exactly(550),
+                    RvrValueInner::Exactly(800)
+                )),
+                unit: RvrUnit::Metres,
+                trend: Data::Known(RvrTrend::Upwards),
+            }
+        );
+        assert_eq!(rvr.to_string(), "R06/0550V0800U");
+
+        let rvr = RunwayVisualRange::parse("R24L/M0050VP1500D").unwrap();
+        assert_eq!(
+            rvr.value,
+            Data::Known(RvrValue::Between(
+                RvrValueInner::LessThan(50),
+                RvrValueInner::GreaterThan(1500)
+            ))
+        );
+        assert_eq!(rvr.trend, Data::Known(RvrTrend::Downwards));
+        assert_eq!(rvr.to_string(), "R24L/M0050VP1500D");
+    }
 }
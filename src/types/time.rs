@@ -14,14 +14,49 @@ pub struct Time {
     pub hour: u8,
     /// The minute the METAR was made
     pub minute: u8,
+    /// The second the METAR was made, if given (`DDHHMMSSZ`)
+    pub second: Option<u8>,
+}
+
+impl Time {
+    /// Resolve the date of this observation against a given year and month,
+    /// returning `(year, month, day)`.
+    ///
+    /// METARs only carry the day of the month, so the year and month must be
+    /// supplied. Returns [`None`] if the month is invalid or does not have
+    /// this many days.
+    #[must_use]
+    pub fn resolve(&self, year: i32, month: u32) -> Option<(i32, u32, u32)> {
+        let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        let day = u32::from(self.date);
+        if day == 0 || day > days_in_month {
+            return None;
+        }
+        Some((year, month, day))
+    }
 }
 
 impl Parsable for Time {
     fn parser<'src>() -> impl chumsky::Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
         let two_digits = text::digits(10).exactly(2).to_slice();
 
-        group((two_digits, two_digits, two_digits, just("Z"))).try_map(
-            |(d, h, m, _): (&str, &str, &str, &str), span| {
+        group((
+            two_digits,
+            two_digits,
+            two_digits,
+            two_digits.or_not(),
+            just("Z"),
+        ))
+        .try_map(
+            |(d, h, m, s, _): (&str, &str, &str, Option<&str>, &str), span| {
                 let date = d
                     .parse()
                     .map_err(|_| ErrorVariant::InvalidDate.into_err(span))?;
@@ -43,7 +78,21 @@ impl Parsable for Time {
                     return Err(ErrorVariant::InvalidMinute.into_err(span));
                 }
 
-                Ok(Time { date, hour, minute })
+                let second = s
+                    .map(|s| {
+                        s.parse()
+                            .ok()
+                            .filter(|second| *second < 60)
+                            .ok_or_else(|| ErrorVariant::InvalidSecond.into_err(span))
+                    })
+                    .transpose()?;
+
+                Ok(Time {
+                    date,
+                    hour,
+                    minute,
+                    second,
+                })
             },
         )
     }
@@ -51,7 +100,11 @@ impl Parsable for Time {
 
 impl Display for Time {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02}{:02}{:02}Z", self.date, self.hour, self.minute)
+        write!(f, "{:02}{:02}{:02}", self.date, self.hour, self.minute)?;
+        if let Some(second) = self.second {
+            write!(f, "{second:02}")?;
+        }
+        f.write_str("Z")
     }
 }
 
@@ -67,10 +120,32 @@ mod tests {
                 date: 1,
                 hour: 1,
                 minute: 1,
+                second: None,
+            }
+        );
+        assert_eq!(
+            Time::parse("01010130Z").unwrap(),
+            Time {
+                date: 1,
+                hour: 1,
+                minute: 1,
+                second: Some(30),
             }
         );
     }
 
+    #[test]
+    fn invalid_second() {
+        assert!(Time::parse("01010160Z").is_err());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for time in ["010101Z", "01010130Z"] {
+            assert_eq!(Time::parse(time).unwrap().to_string(), time);
+        }
+    }
+
     #[test]
     fn invalid_date() {
         assert!(Time::parse("320101Z").is_err());
@@ -85,4 +160,16 @@ mod tests {
     fn invalid_minute() {
         assert!(Time::parse("010160Z").is_err());
     }
+
+    #[test]
+    fn resolve() {
+        let time = Time::parse("310101Z").unwrap();
+        assert_eq!(time.resolve(2024, 1), Some((2024, 1, 31)));
+        assert_eq!(time.resolve(2024, 4), None);
+        assert_eq!(time.resolve(2024, 13), None);
+
+        let time = Time::parse("290101Z").unwrap();
+        assert_eq!(time.resolve(2024, 2), Some((2024, 2, 29)));
+        assert_eq!(time.resolve(2023, 2), None);
+    }
 }
@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 use chumsky::prelude::*;
 
 use crate::{
-    CloudLayer, VerticalVisibility, Visibility, Weather, Wind,
+    CloudLayer, ColourCode, Data, VerticalVisibility, Visibility, Weather, Wind,
     parsers::{any_whitespace, some_whitespace},
     traits::Parsable,
 };
@@ -61,6 +61,8 @@ pub struct TrendNewCondition {
     pub cloud: Vec<CloudLayer>,
     /// New vertical visibility, if specified
     pub vertical_visibility: Option<VerticalVisibility>,
+    /// New military airport colour code, if specified
+    pub colour_code: Option<Data<ColourCode>>,
 }
 
 impl Parsable for TrendNewCondition {
@@ -79,6 +81,7 @@ impl Parsable for TrendNewCondition {
             choice((
                 just("NSW").map(|_| vec![]).then_ignore(any_whitespace()),
                 Weather::parser()
+                    .then_ignore(some_whitespace().rewind())
                     .separated_by(some_whitespace())
                     .allow_trailing()
                     .collect::<Vec<_>>(),
@@ -91,15 +94,22 @@ impl Parsable for TrendNewCondition {
                 .then_ignore(any_whitespace())
                 .map(Some)
                 .or(empty().map(|()| None)),
+            Data::parser_inline(3, ColourCode::parser())
+                .then_ignore(any_whitespace())
+                .map(Some)
+                .or(empty().map(|()| None)),
         ))
         .map(
-            |(time, wind, visibility, weather, cloud, vertical_visibility)| TrendNewCondition {
-                time,
-                wind,
-                visibility,
-                weather,
-                cloud,
-                vertical_visibility,
+            |(time, wind, visibility, weather, cloud, vertical_visibility, colour_code)| {
+                TrendNewCondition {
+                    time,
+                    wind,
+                    visibility,
+                    weather,
+                    cloud,
+                    vertical_visibility,
+                    colour_code,
+                }
             },
         )
     }
@@ -125,6 +135,9 @@ impl Display for TrendNewCondition {
         if let Some(vv) = self.vertical_visibility {
             write!(f, " {vv}")?;
         }
+        if let Some(colour) = &self.colour_code {
+            write!(f, " {}", colour.to_opt_string(3))?;
+        }
 
         Ok(())
     }
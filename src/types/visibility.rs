@@ -2,7 +2,7 @@ use std::fmt::{self, Display, Formatter};
 
 use chumsky::prelude::*;
 
-use crate::{Data, parsers::some_whitespace, traits::Parsable};
+use crate::{Data, VisibilityUnit, parsers::some_whitespace, traits::Parsable};
 
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[allow(missing_docs, reason = "clear what they are!")]
@@ -56,9 +56,17 @@ pub enum Visibility {
     /// Visibility OK
     CAVOK,
     /// Metres
+    ///
+    /// Visibility given in kilometres (e.g. `10KM`) is normalised to metres,
+    /// so will be displayed in metres rather than as it was originally given.
+    /// Anything more than 10km is stored as 10km. As a visibility group only
+    /// has four digits, 10km or more is displayed as `9999`, which means the
+    /// same thing, so this normalisation loses information.
     Metres(u16),
     /// Statute miles, usually used in the US
     StatuteMiles(f32),
+    /// More than the given number of statute miles, e.g. the US `P6SM`
+    MoreThanStatuteMiles(f32),
 }
 
 impl Parsable for Visibility {
@@ -74,6 +82,21 @@ impl Parsable for Visibility {
                 .exactly(4)
                 .to_slice()
                 .map(|digits: &str| Visibility::Metres(digits.parse().unwrap())),
+            // Kilometres
+            text::digits(10)
+                .at_least(1)
+                .at_most(2)
+                .to_slice()
+                .then_ignore(just("KM"))
+                .map(|digits: &str| {
+                    let km: u16 = digits.parse().unwrap();
+                    Visibility::Metres(km.min(10) * 1000)
+                }),
+            // More than whole miles
+            just("P")
+                .ignore_then(text::digits(10).at_least(1).at_most(2).to_slice())
+                .then_ignore(just("SM"))
+                .map(|digits: &str| Visibility::MoreThanStatuteMiles(digits.parse().unwrap())),
             // Whole miles
             text::digits(10)
                 .at_least(1)
@@ -121,6 +144,85 @@ impl Parsable for Visibility {
     }
 }
 
+/// The number of metres in a statute mile
+const METRES_PER_STATUTE_MILE: f32 = 1609.344;
+
+impl Visibility {
+    /// This visibility in statute miles, or [`None`] for CAVOK.
+    #[must_use]
+    pub fn statute_miles(&self) -> Option<f32> {
+        match self {
+            Visibility::CAVOK => None,
+            Visibility::Metres(m) => Some(f32::from(*m) / METRES_PER_STATUTE_MILE),
+            Visibility::StatuteMiles(sm) | Visibility::MoreThanStatuteMiles(sm) => Some(*sm),
+        }
+    }
+
+    /// This visibility in metres. CAVOK is treated as 10km.
+    #[must_use]
+    pub fn metres(&self) -> f32 {
+        match self {
+            Visibility::CAVOK => 10_000.,
+            Visibility::Metres(m) => f32::from(*m),
+            Visibility::StatuteMiles(sm) | Visibility::MoreThanStatuteMiles(sm) => {
+                sm * METRES_PER_STATUTE_MILE
+            }
+        }
+    }
+
+    /// Whether this visibility is 10km or more. A visibility of `9999` is
+    /// the conventional way to report this in metres, so is not an exact
+    /// distance, and CAVOK implies it.
+    #[must_use]
+    pub fn is_ten_km_or_more(&self) -> bool {
+        self.metres() >= 9999.
+    }
+
+    /// Whether this visibility is reported as effectively unlimited: CAVOK,
+    /// `9999` metres (10km or more), or more than 6 statute miles, such as
+    /// the US `P6SM` or `10SM`.
+    #[must_use]
+    pub fn is_unlimited(&self) -> bool {
+        match self {
+            Visibility::CAVOK => true,
+            Visibility::Metres(m) => *m >= 9999,
+            Visibility::StatuteMiles(sm) => *sm > 6.,
+            Visibility::MoreThanStatuteMiles(sm) => *sm >= 6.,
+        }
+    }
+
+    /// Convert this visibility into the given unit.
+    ///
+    /// Metres are capped at 9999, and statute miles are rounded to the
+    /// nearest quarter below 3SM and the nearest mile above. 9999 metres,
+    /// meaning 10km or more, is converted to 10SM.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "visibility is clamped to fit in the range of a u16"
+    )]
+    pub(crate) fn to_unit(self, unit: VisibilityUnit) -> Visibility {
+        match (self, unit) {
+            (
+                Visibility::StatuteMiles(sm) | Visibility::MoreThanStatuteMiles(sm),
+                VisibilityUnit::Metres,
+            ) => Visibility::Metres((sm * METRES_PER_STATUTE_MILE).round().clamp(0., 9999.) as u16),
+            (Visibility::Metres(9999..), VisibilityUnit::StatuteMiles) => {
+                Visibility::StatuteMiles(10.)
+            }
+            (Visibility::Metres(m), VisibilityUnit::StatuteMiles) => {
+                let sm = f32::from(m) / METRES_PER_STATUTE_MILE;
+                Visibility::StatuteMiles(if sm < 3. {
+                    (sm * 4.).round() / 4.
+                } else {
+                    sm.round()
+                })
+            }
+            (vis, _) => vis,
+        }
+    }
+}
+
 impl Parsable for (Option<CompassDirection>, Data<Visibility>) {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         group((
@@ -138,9 +240,33 @@ impl Display for Visibility {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Visibility::CAVOK => f.write_str("CAVOK"),
-            Visibility::Metres(m) => write!(f, "{m:04}"),
-            // FIXME fractions
-            Visibility::StatuteMiles(sm) => write!(f, "{sm}SM"),
+            // 10km or more is written as 9999
+            Visibility::Metres(m) => write!(f, "{:04}", m.min(&9999)),
+            Visibility::MoreThanStatuteMiles(sm) => write!(f, "P{sm}SM"),
+            Visibility::StatuteMiles(sm) => {
+                let whole = sm.trunc();
+                let fraction = sm - whole;
+                if fraction.abs() < f32::EPSILON {
+                    return write!(f, "{whole}SM");
+                }
+                // Fractions are written as they are reported, e.g. `1 3/4SM`,
+                // but a mixed fraction may only have a single digit denominator
+                for denominator in [2., 4., 8., 16.] {
+                    let numerator = fraction * denominator;
+                    if (numerator - numerator.round()).abs() > 0.001
+                        || (whole > 0. && denominator > 9.)
+                    {
+                        continue;
+                    }
+                    let numerator = numerator.round();
+                    return if whole > 0. {
+                        write!(f, "{whole} {numerator}/{denominator}SM")
+                    } else {
+                        write!(f, "{numerator}/{denominator}SM")
+                    };
+                }
+                write!(f, "{sm}SM")
+            }
         }
     }
 }
@@ -153,6 +279,19 @@ mod tests {
     fn valid_visibility() {
         assert_eq!(Visibility::parse("CAVOK").unwrap(), Visibility::CAVOK);
         assert_eq!(Visibility::parse("5000").unwrap(), Visibility::Metres(5000));
+        let p6sm = Visibility::parse("P6SM").unwrap();
+        assert_eq!(p6sm, Visibility::MoreThanStatuteMiles(6.));
+        assert_eq!(p6sm.to_string(), "P6SM");
+        assert_eq!(
+            Visibility::parse("10KM").unwrap(),
+            Visibility::Metres(10000)
+        );
+        assert_eq!(
+            Visibility::parse("99KM").unwrap(),
+            Visibility::Metres(10000)
+        );
+        assert_eq!(Visibility::Metres(10000).to_string(), "9999");
+        assert_eq!(Visibility::parse("5KM").unwrap(), Visibility::Metres(5000));
         assert_eq!(
             Visibility::parse("3SM").unwrap(),
             Visibility::StatuteMiles(3.)
@@ -166,4 +305,88 @@ mod tests {
             Visibility::StatuteMiles(3.5)
         );
     }
+
+    #[test]
+    fn display_fractions() {
+        for vis in ["3/4SM", "1 3/4SM", "1/2SM", "1/16SM", "2 1/2SM", "10SM"] {
+            assert_eq!(Visibility::parse(vis).unwrap().to_string(), vis);
+        }
+        assert_eq!(
+            Visibility::parse("3/4SM").unwrap(),
+            Visibility::StatuteMiles(0.75)
+        );
+        assert_eq!(
+            Visibility::parse("1 3/4SM").unwrap(),
+            Visibility::StatuteMiles(1.75)
+        );
+    }
+
+    #[test]
+    fn display_keeps_unit() {
+        for sm in [0.25, 0.5, 1., 1.5, 3., 6., 10., 15.] {
+            let vis = Visibility::StatuteMiles(sm);
+            let displayed = vis.to_string();
+            assert!(displayed.ends_with("SM"), "{displayed}");
+            assert_eq!(Visibility::parse(&displayed).unwrap(), vis);
+        }
+        for m in [0, 50, 800, 1500, 9999] {
+            let vis = Visibility::Metres(m);
+            let displayed = vis.to_string();
+            assert!(displayed.bytes().all(|b| b.is_ascii_digit()), "{displayed}");
+            assert_eq!(Visibility::parse(&displayed).unwrap(), vis);
+        }
+    }
+
+    #[test]
+    fn metres() {
+        assert!((Visibility::CAVOK.metres() - 10_000.).abs() < f32::EPSILON);
+        assert!((Visibility::Metres(9999).metres() - 9999.).abs() < f32::EPSILON);
+        assert!((Visibility::StatuteMiles(1.).metres() - 1609.344).abs() < 0.01);
+    }
+
+    #[test]
+    fn is_ten_km_or_more() {
+        assert!(Visibility::Metres(9999).is_ten_km_or_more());
+        assert!(Visibility::Metres(10000).is_ten_km_or_more());
+        assert!(Visibility::CAVOK.is_ten_km_or_more());
+        assert!(Visibility::StatuteMiles(10.).is_ten_km_or_more());
+        assert!(!Visibility::Metres(9000).is_ten_km_or_more());
+        assert!(!Visibility::StatuteMiles(5.).is_ten_km_or_more());
+    }
+
+    #[test]
+    fn is_unlimited() {
+        assert!(Visibility::CAVOK.is_unlimited());
+        assert!(Visibility::parse("9999").unwrap().is_unlimited());
+        assert!(Visibility::parse("10SM").unwrap().is_unlimited());
+        assert!(Visibility::parse("P6SM").unwrap().is_unlimited());
+        assert!(Visibility::parse("10KM").unwrap().is_unlimited());
+        assert!(!Visibility::parse("8000").unwrap().is_unlimited());
+        assert!(!Visibility::parse("6SM").unwrap().is_unlimited());
+        assert!(!Visibility::parse("1 1/2SM").unwrap().is_unlimited());
+    }
+
+    #[test]
+    fn to_unit() {
+        assert_eq!(
+            Visibility::StatuteMiles(3.).to_unit(VisibilityUnit::Metres),
+            Visibility::Metres(4828)
+        );
+        assert_eq!(
+            Visibility::StatuteMiles(10.).to_unit(VisibilityUnit::Metres),
+            Visibility::Metres(9999)
+        );
+        assert_eq!(
+            Visibility::Metres(9999).to_unit(VisibilityUnit::StatuteMiles),
+            Visibility::StatuteMiles(10.)
+        );
+        assert_eq!(
+            Visibility::Metres(1200).to_unit(VisibilityUnit::StatuteMiles),
+            Visibility::StatuteMiles(0.75)
+        );
+        assert_eq!(
+            Visibility::CAVOK.to_unit(VisibilityUnit::StatuteMiles),
+            Visibility::CAVOK
+        );
+    }
 }
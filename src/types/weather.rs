@@ -18,6 +18,54 @@ pub struct Weather {
     pub conditions: Vec<WeatherCondition>,
 }
 
+impl Weather {
+    /// Whether this weather is considered operationally significant.
+    ///
+    /// Weather is significant if it is heavy, or if it includes any of:
+    /// thunderstorms, freezing phenomena, hail, snow pellets, ice pellets,
+    /// unknown precipitation, fog, volcanic ash, squalls, funnel clouds, or
+    /// dust or sand storms. Recent weather is never significant, as it no
+    /// longer affects the station.
+    #[must_use]
+    pub fn is_significant(&self) -> bool {
+        match self.intensity {
+            WeatherIntensity::Recent => false,
+            WeatherIntensity::Heavy => true,
+            _ => self.conditions.iter().any(|condition| {
+                matches!(
+                    condition,
+                    WeatherCondition::Thunderstorm
+                        | WeatherCondition::Freezing
+                        | WeatherCondition::Hail
+                        | WeatherCondition::SnowPelletsOrSmallHail
+                        | WeatherCondition::IcePellets
+                        | WeatherCondition::UnknownPrecipitation
+                        | WeatherCondition::Fog
+                        | WeatherCondition::VolcanicAsh
+                        | WeatherCondition::Squall
+                        | WeatherCondition::FunnelCloud
+                        | WeatherCondition::Duststorm
+                        | WeatherCondition::Sandstorm
+                )
+            }),
+        }
+    }
+
+    /// Whether the intensity of this weather is permitted. Only
+    /// precipitation, duststorms, sandstorms and funnel clouds may be light
+    /// (`-`) or heavy (`+`).
+    #[must_use]
+    pub fn has_valid_intensity(&self) -> bool {
+        !matches!(
+            self.intensity,
+            WeatherIntensity::Light | WeatherIntensity::Heavy
+        ) || self
+            .conditions
+            .iter()
+            .any(WeatherCondition::accepts_intensity)
+    }
+}
+
 impl Parsable for Weather {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         WeatherIntensity::parser()
@@ -65,5 +113,55 @@ mod tests {
                 conditions: vec![WeatherCondition::Thunderstorm,]
             }
         );
+        assert_eq!(
+            Weather::parse("VCSH").unwrap(),
+            Weather {
+                intensity: WeatherIntensity::InVicinity,
+                conditions: vec![WeatherCondition::Showers]
+            }
+        );
+        assert_eq!(
+            Weather::parse("VCFG").unwrap(),
+            Weather {
+                intensity: WeatherIntensity::InVicinity,
+                conditions: vec![WeatherCondition::Fog]
+            }
+        );
+        assert_eq!(
+            Weather::parse("+TSRA").unwrap(),
+            Weather {
+                intensity: WeatherIntensity::Heavy,
+                conditions: vec![WeatherCondition::Thunderstorm, WeatherCondition::Rain]
+            }
+        );
+        assert_eq!(
+            Weather::parse("//RA").unwrap(),
+            Weather {
+                intensity: WeatherIntensity::Unknown,
+                conditions: vec![WeatherCondition::Rain]
+            }
+        );
+    }
+
+    #[test]
+    fn test_has_valid_intensity() {
+        assert!(!Weather::parse("+FG").unwrap().has_valid_intensity());
+        assert!(!Weather::parse("-BR").unwrap().has_valid_intensity());
+        assert!(!Weather::parse("+TS").unwrap().has_valid_intensity());
+        assert!(Weather::parse("+FC").unwrap().has_valid_intensity());
+        assert!(Weather::parse("+SS").unwrap().has_valid_intensity());
+        assert!(Weather::parse("-SHSN").unwrap().has_valid_intensity());
+        assert!(Weather::parse("VCFG").unwrap().has_valid_intensity());
+    }
+
+    #[test]
+    fn test_is_significant() {
+        assert!(!Weather::parse("-RA").unwrap().is_significant());
+        assert!(!Weather::parse("BR").unwrap().is_significant());
+        assert!(Weather::parse("+TSRA").unwrap().is_significant());
+        assert!(Weather::parse("+RA").unwrap().is_significant());
+        assert!(Weather::parse("-FZDZ").unwrap().is_significant());
+        assert!(Weather::parse("VCFG").unwrap().is_significant());
+        assert!(!Weather::parse("RETS").unwrap().is_significant());
     }
 }
@@ -70,49 +70,86 @@ pub enum WeatherCondition {
     FunnelCloud,
 }
 
-impl Parsable for WeatherCondition {
-    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        choice((
-            just("MI").map(|_| WeatherCondition::Shallow),
-            just("PR").map(|_| WeatherCondition::Partial),
-            just("BC").map(|_| WeatherCondition::Patches),
-            just("DR").map(|_| WeatherCondition::LowDrifting),
-            just("BL").map(|_| WeatherCondition::Blowing),
-            just("SH").map(|_| WeatherCondition::Showers),
-            just("TS").map(|_| WeatherCondition::Thunderstorm),
-            just("FZ").map(|_| WeatherCondition::Freezing),
-            just("RA").map(|_| WeatherCondition::Rain),
-            just("DZ").map(|_| WeatherCondition::Drizzle),
-            just("SN").map(|_| WeatherCondition::Snow),
-            just("SG").map(|_| WeatherCondition::SnowGrains),
-            just("IC").map(|_| WeatherCondition::IceCrystals),
-            just("PL").map(|_| WeatherCondition::IcePellets),
-            just("GR").map(|_| WeatherCondition::Hail),
-            just("GS").map(|_| WeatherCondition::SnowPelletsOrSmallHail),
-            just("UP").map(|_| WeatherCondition::UnknownPrecipitation),
-            just("FG").map(|_| WeatherCondition::Fog),
-            just("VA").map(|_| WeatherCondition::VolcanicAsh),
-            just("BR").map(|_| WeatherCondition::Mist),
-            just("HZ").map(|_| WeatherCondition::Haze),
-            just("DU").map(|_| WeatherCondition::WidespreadDust),
-            just("FU").map(|_| WeatherCondition::Smoke),
-            just("SA").map(|_| WeatherCondition::Sand),
-            just("PY").map(|_| WeatherCondition::Spray),
-            just("SQ").map(|_| WeatherCondition::Squall),
-            // Too many for one choice, split into two!
-        ))
-        .or(choice((
-            just("PO").map(|_| WeatherCondition::Dust),
-            just("DS").map(|_| WeatherCondition::Duststorm),
-            just("SS").map(|_| WeatherCondition::Sandstorm),
-            just("FC").map(|_| WeatherCondition::FunnelCloud),
-        )))
+impl WeatherCondition {
+    /// Every weather condition, in the order they are tried when parsing
+    pub(crate) const ALL: [WeatherCondition; 30] = [
+        WeatherCondition::Shallow,
+        WeatherCondition::Partial,
+        WeatherCondition::Patches,
+        WeatherCondition::LowDrifting,
+        WeatherCondition::Blowing,
+        WeatherCondition::Showers,
+        WeatherCondition::Thunderstorm,
+        WeatherCondition::Freezing,
+        WeatherCondition::Rain,
+        WeatherCondition::Drizzle,
+        WeatherCondition::Snow,
+        WeatherCondition::SnowGrains,
+        WeatherCondition::IceCrystals,
+        WeatherCondition::IcePellets,
+        WeatherCondition::Hail,
+        WeatherCondition::SnowPelletsOrSmallHail,
+        WeatherCondition::UnknownPrecipitation,
+        WeatherCondition::Fog,
+        WeatherCondition::VolcanicAsh,
+        WeatherCondition::Mist,
+        WeatherCondition::Haze,
+        WeatherCondition::WidespreadDust,
+        WeatherCondition::Smoke,
+        WeatherCondition::Sand,
+        WeatherCondition::Spray,
+        WeatherCondition::Squall,
+        WeatherCondition::Dust,
+        WeatherCondition::Duststorm,
+        WeatherCondition::Sandstorm,
+        WeatherCondition::FunnelCloud,
+    ];
+
+    /// The weather condition for a two letter code, e.g. `RA`, or [`None`]
+    /// if the code is not recognised.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        WeatherCondition::ALL
+            .iter()
+            .copied()
+            .find(|condition| condition.code() == code)
     }
-}
 
-impl Display for WeatherCondition {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
+    /// Whether this condition is a type of precipitation, e.g. `RA` or `SN`
+    #[must_use]
+    pub fn is_precipitation(&self) -> bool {
+        matches!(
+            self,
+            WeatherCondition::Rain
+                | WeatherCondition::Drizzle
+                | WeatherCondition::Snow
+                | WeatherCondition::SnowGrains
+                | WeatherCondition::IceCrystals
+                | WeatherCondition::IcePellets
+                | WeatherCondition::Hail
+                | WeatherCondition::SnowPelletsOrSmallHail
+                | WeatherCondition::UnknownPrecipitation
+        )
+    }
+
+    /// Whether a light (`-`) or heavy (`+`) intensity may be given for this
+    /// condition. This is the case for precipitation, as well as duststorms,
+    /// sandstorms and funnel clouds (`+FC` being a tornado or waterspout).
+    #[must_use]
+    pub fn accepts_intensity(&self) -> bool {
+        self.is_precipitation()
+            || matches!(
+                self,
+                WeatherCondition::Duststorm
+                    | WeatherCondition::Sandstorm
+                    | WeatherCondition::FunnelCloud
+            )
+    }
+
+    /// The two letter code for this weather condition, e.g. `RA`
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
             WeatherCondition::Shallow => "MI",
             WeatherCondition::Partial => "PR",
             WeatherCondition::Patches => "BC",
@@ -127,7 +164,7 @@ impl Display for WeatherCondition {
             WeatherCondition::SnowGrains => "SG",
             WeatherCondition::IceCrystals => "IC",
             WeatherCondition::IcePellets => "PL",
-            WeatherCondition::Hail => "HR",
+            WeatherCondition::Hail => "GR",
             WeatherCondition::SnowPelletsOrSmallHail => "GS",
             WeatherCondition::UnknownPrecipitation => "UP",
             WeatherCondition::Fog => "FG",
@@ -143,6 +180,66 @@ impl Display for WeatherCondition {
             WeatherCondition::Duststorm => "DS",
             WeatherCondition::Sandstorm => "SS",
             WeatherCondition::FunnelCloud => "FC",
-        })
+        }
+    }
+}
+
+impl Parsable for WeatherCondition {
+    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
+        choice(WeatherCondition::ALL.map(|condition| just(condition.code()).to(condition)))
+    }
+}
+
+impl Display for WeatherCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_code() {
+        assert_eq!(
+            WeatherCondition::from_code("RA"),
+            Some(WeatherCondition::Rain)
+        );
+        assert_eq!(
+            WeatherCondition::from_code("FG"),
+            Some(WeatherCondition::Fog)
+        );
+        assert_eq!(
+            WeatherCondition::from_code("GR"),
+            Some(WeatherCondition::Hail)
+        );
+        assert_eq!(WeatherCondition::Hail.to_string(), "GR");
+        assert_eq!(WeatherCondition::from_code("XX"), None);
+        assert_eq!(WeatherCondition::from_code("RAIN"), None);
+    }
+
+    #[test]
+    fn accepts_intensity() {
+        assert!(WeatherCondition::Rain.is_precipitation());
+        assert!(WeatherCondition::Rain.accepts_intensity());
+        assert!(!WeatherCondition::Sandstorm.is_precipitation());
+        assert!(WeatherCondition::Sandstorm.accepts_intensity());
+        assert!(!WeatherCondition::Fog.accepts_intensity());
+        assert!(!WeatherCondition::Thunderstorm.accepts_intensity());
+    }
+
+    #[test]
+    fn code_round_trip() {
+        for condition in WeatherCondition::ALL {
+            assert_eq!(
+                WeatherCondition::from_code(condition.code()),
+                Some(condition)
+            );
+            assert_eq!(
+                WeatherCondition::parse(condition.code()).unwrap(),
+                condition
+            );
+        }
     }
 }
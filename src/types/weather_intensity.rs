@@ -18,6 +18,8 @@ pub enum WeatherIntensity {
     InVicinity,
     /// Recent (RE)
     Recent,
+    /// Unknown, usually from automated stations (//)
+    Unknown,
 }
 
 impl Parsable for WeatherIntensity {
@@ -27,6 +29,7 @@ impl Parsable for WeatherIntensity {
             just("+").map(|_| WeatherIntensity::Heavy),
             just("VC").map(|_| WeatherIntensity::InVicinity),
             just("RE").map(|_| WeatherIntensity::Recent),
+            just("//").map(|_| WeatherIntensity::Unknown),
             empty().map(|()| WeatherIntensity::Moderate),
         ))
     }
@@ -40,6 +43,7 @@ impl Display for WeatherIntensity {
             WeatherIntensity::Heavy => f.write_str("+"),
             WeatherIntensity::InVicinity => f.write_str("VC"),
             WeatherIntensity::Recent => f.write_str("RE"),
+            WeatherIntensity::Unknown => f.write_str("//"),
         }
     }
 }
@@ -3,12 +3,14 @@ use std::fmt::Formatter;
 
 use chumsky::prelude::*;
 
+use crate::ErrorVariant;
 use crate::parsers::some_whitespace;
 use crate::traits::Parsable;
 
 use super::Data;
 use super::WindDirection;
 use super::WindSpeed;
+use super::wind_direction::heading;
 
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -37,9 +39,7 @@ impl Parsable for Wind {
                 WindDirection::parser(),
                 WindSpeed::parser().then_ignore(some_whitespace()),
                 choice((
-                    group((WindDirection::parser(), just("V"), WindDirection::parser()))
-                        .map(|(from, _, to)| Some((from.unwrap_heading(), to.unwrap_heading())))
-                        .then_ignore(some_whitespace()),
+                    varying().map(Some).then_ignore(some_whitespace()),
                     empty().map(|()| None),
                 )),
             ))
@@ -52,6 +52,41 @@ impl Parsable for Wind {
     }
 }
 
+/// Parse a wind whose gust is missing its `G` separator, e.g. `25015928KT`.
+///
+/// Unless `recover` is set, an error is emitted so that the corrupted gust is
+/// reported clearly rather than as a generic parsing failure.
+pub(crate) fn missing_gust_separator<'src>(
+    recover: bool,
+) -> impl Parser<'src, &'src str, Wind, extra::Err<crate::MetarError<'src>>> {
+    group((
+        WindDirection::parser(),
+        super::wind_speed::missing_gust_separator()
+            .validate(move |speed, e, emitter| {
+                if !recover {
+                    emitter.emit(ErrorVariant::MissingGustSeparator.into_err(e.span()));
+                }
+                speed
+            })
+            .then_ignore(some_whitespace()),
+        choice((
+            varying().map(Some).then_ignore(some_whitespace()),
+            empty().map(|()| None),
+        )),
+    ))
+    .map(|(dir, speed, varying)| Wind::Present {
+        dir,
+        speed,
+        varying,
+    })
+}
+
+/// Parse the range of headings the wind is varying between, e.g. `140V220`
+pub(crate) fn varying<'src>()
+-> impl Parser<'src, &'src str, (Data<u32>, Data<u32>), extra::Err<crate::MetarError<'src>>> {
+    group((heading(), just("V"), heading())).map(|(from, _, to)| (from, to))
+}
+
 impl Display for Wind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -2,44 +2,69 @@ use std::fmt::{Display, Formatter};
 
 use chumsky::prelude::*;
 
-use crate::{Data, ErrorVariant, MetarError, traits::Parsable};
+use crate::{CompassDirection, Data, ErrorVariant, MetarError, traits::Parsable};
 
 /// A representation of wind direction
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindDirection {
     /// A heading defining wind direction
+    ///
+    /// An unknown direction marked as "no direction" (`///ND`) is normalised
+    /// to an unknown heading, so will be displayed as `///`.
     Heading(Data<u32>),
     /// Wind direction is variable
     Variable,
 }
 
 impl WindDirection {
-    pub(crate) fn unwrap_heading(self) -> Data<u32> {
-        match self {
-            Self::Heading(v) => v,
-            Self::Variable => panic!(),
-        }
+    /// The nearest 8-point compass direction to this heading, or [`None`] if
+    /// the wind is variable or the heading is unknown.
+    #[must_use]
+    pub fn compass(&self) -> Option<CompassDirection> {
+        let WindDirection::Heading(Data::Known(hdg)) = self else {
+            return None;
+        };
+        Some(match (hdg % 360 + 22) / 45 % 8 {
+            0 => CompassDirection::North,
+            1 => CompassDirection::NorthEast,
+            2 => CompassDirection::East,
+            3 => CompassDirection::SouthEast,
+            4 => CompassDirection::South,
+            5 => CompassDirection::SouthWest,
+            6 => CompassDirection::West,
+            _ => CompassDirection::NorthWest,
+        })
     }
 }
 
+/// Parse a three digit heading, which may be unknown (`///`)
+pub(crate) fn heading<'src>()
+-> impl chumsky::Parser<'src, &'src str, Data<u32>, extra::Err<MetarError<'src>>> {
+    Data::parser_inline(
+        3,
+        text::digits(10)
+            .exactly(3)
+            .to_slice()
+            .try_map(|hdg: &str, span| {
+                let hdg = hdg
+                    .parse()
+                    .map_err(|_| ErrorVariant::InvalidWindHeading.into_err(span))?;
+                if hdg > 360 {
+                    return Err(ErrorVariant::InvalidWindHeading.into_err(span));
+                }
+                Ok(hdg)
+            }),
+    )
+}
+
 impl Parsable for WindDirection {
     fn parser<'src>() -> impl chumsky::Parser<'src, &'src str, Self, extra::Err<MetarError<'src>>> {
         choice((
             just("VRB").map(|_| WindDirection::Variable),
-            just("///").map(|_| WindDirection::Heading(Data::Unknown)),
-            text::digits(10)
-                .exactly(3)
-                .to_slice()
-                .try_map(|hdg: &str, span| {
-                    let hdg = hdg
-                        .parse()
-                        .map_err(|_| ErrorVariant::InvalidWindHeading.into_err(span))?;
-                    if hdg > 360 {
-                        return Err(ErrorVariant::InvalidWindHeading.into_err(span));
-                    }
-                    Ok(WindDirection::Heading(Data::Known(hdg)))
-                }),
+            // Some offshore reports mark an unknown direction as "no direction"
+            just("///ND").map(|_| WindDirection::Heading(Data::Unknown)),
+            heading().map(WindDirection::Heading),
         ))
     }
 }
@@ -72,6 +97,9 @@ mod tests {
             WindDirection::parse("///").unwrap(),
             WindDirection::Heading(Data::Unknown)
         );
+        let no_direction = WindDirection::parse("///ND").unwrap();
+        assert_eq!(no_direction, WindDirection::Heading(Data::Unknown));
+        assert_eq!(no_direction.to_string(), "///");
     }
 
     #[test]
@@ -81,4 +109,15 @@ mod tests {
             WindDirection::Variable
         );
     }
+
+    #[test]
+    fn compass() {
+        let compass = |hdg| WindDirection::Heading(Data::Known(hdg)).compass();
+        assert_eq!(compass(45), Some(CompassDirection::NorthEast));
+        assert_eq!(compass(350), Some(CompassDirection::North));
+        assert_eq!(compass(360), Some(CompassDirection::North));
+        assert_eq!(compass(200), Some(CompassDirection::South));
+        assert_eq!(WindDirection::Variable.compass(), None);
+        assert_eq!(WindDirection::Heading(Data::Unknown).compass(), None);
+    }
 }
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use chumsky::prelude::*;
 
-use crate::{Data, MetarError, traits::Parsable};
+use crate::{Data, ErrorVariant, MetarError, traits::Parsable};
 
 /// The wind speed
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
@@ -31,6 +31,127 @@ pub enum WindSpeed {
     },
     /// Wind speed is greater than 100 knots, 100 m/s or 200 kph
     Greater,
+    /// Wind speed is at least the given speed, e.g. `P49KT`
+    AtLeast {
+        /// The minimum wind speed
+        speed: u32,
+        /// The unit the wind speed is given in
+        unit: WindUnit,
+    },
+}
+
+impl WindSpeed {
+    /// The unit this wind speed was given in, if known.
+    ///
+    /// [`WindSpeed::Greater`] does not retain its unit, so returns [`None`].
+    #[must_use]
+    pub fn unit(&self) -> Option<WindUnit> {
+        match self {
+            WindSpeed::Knots { .. } => Some(WindUnit::Knots),
+            WindSpeed::MetresPerSecond { .. } => Some(WindUnit::MetresPerSecond),
+            WindSpeed::KilometresPerHour { .. } => Some(WindUnit::KilometresPerHour),
+            WindSpeed::AtLeast { unit, .. } => Some(*unit),
+            WindSpeed::Greater => None,
+        }
+    }
+
+    /// The sustained wind speed converted to knots, or [`None`] if it is
+    /// unknown or only known to be greater than the maximum.
+    ///
+    /// For [`WindSpeed::AtLeast`], the minimum speed is used.
+    #[must_use]
+    pub fn knots(&self) -> Option<f32> {
+        let (speed, unit) = match self {
+            WindSpeed::Knots {
+                speed: Data::Known(speed),
+                ..
+            } => (*speed, WindUnit::Knots),
+            WindSpeed::MetresPerSecond {
+                speed: Data::Known(speed),
+                ..
+            } => (*speed, WindUnit::MetresPerSecond),
+            WindSpeed::KilometresPerHour {
+                speed: Data::Known(speed),
+                ..
+            } => (*speed, WindUnit::KilometresPerHour),
+            WindSpeed::AtLeast { speed, unit } => (*speed, *unit),
+            _ => return None,
+        };
+        Some(unit.to_knots(speed))
+    }
+}
+
+/// The number of metres in a nautical mile
+const METRES_PER_NAUTICAL_MILE: f32 = 1852.;
+
+/// Parse a speed and optional gust, each of `digits` digits, followed by `unit`
+fn speed_and_gust<'src>(
+    digits: usize,
+    unit: &'static str,
+) -> impl Parser<'src, &'src str, (Data<u32>, Option<Data<u32>>), extra::Err<MetarError<'src>>> {
+    let value = || {
+        Data::parser_inline(
+            2,
+            text::digits(10)
+                .exactly(digits)
+                .to_slice()
+                .map(|d: &str| d.parse().unwrap()),
+        )
+    };
+    group((value(), just("G").ignore_then(value()).or_not(), just(unit)))
+        .map(|(speed, gusting, _)| (speed, gusting))
+}
+
+/// Parse a minimum speed of `digits` digits, prefixed by `P` and followed by `unit`
+fn at_least<'src>(
+    digits: usize,
+    unit: WindUnit,
+) -> impl Parser<'src, &'src str, WindSpeed, extra::Err<MetarError<'src>>> {
+    just("P")
+        .ignore_then(
+            text::digits(10)
+                .exactly(digits)
+                .to_slice()
+                .map(|d: &str| d.parse().unwrap()),
+        )
+        .then_ignore(just(match unit {
+            WindUnit::Knots => "KT",
+            WindUnit::MetresPerSecond => "MPS",
+            WindUnit::KilometresPerHour => "KPH",
+        }))
+        .map(move |speed| WindSpeed::AtLeast { speed, unit })
+}
+
+/// Parse a speed and gust where the `G` separating them has been corrupted,
+/// e.g. `15928KT` meaning `15G28KT`. Only recovered when the gust is faster
+/// than the speed.
+pub(crate) fn missing_gust_separator<'src>()
+-> impl Parser<'src, &'src str, WindSpeed, extra::Err<MetarError<'src>>> {
+    let value = || {
+        text::digits(10)
+            .exactly(2)
+            .to_slice()
+            .map(|d: &str| d.parse::<u32>().unwrap())
+    };
+    group((
+        value(),
+        any().filter(|c: &char| c.is_ascii_alphanumeric()),
+        value(),
+        choice((
+            just("KT").map(|_| WindUnit::Knots),
+            just("MPS").map(|_| WindUnit::MetresPerSecond),
+        )),
+    ))
+    .try_map(|(speed, _, gust, unit), span| {
+        if gust <= speed {
+            return Err(ErrorVariant::MissingGustSeparator.into_err(span));
+        }
+        let (speed, gusting) = (Data::Known(speed), Some(Data::Known(gust)));
+        Ok(match unit {
+            WindUnit::MetresPerSecond => WindSpeed::MetresPerSecond { speed, gusting },
+            _ => WindSpeed::Knots { speed, gusting },
+        })
+    })
 }
 
 impl Parsable for WindSpeed {
@@ -40,141 +161,92 @@ impl Parsable for WindSpeed {
             just("P99KT").map(|_| WindSpeed::Greater),
             just("P99MPS").map(|_| WindSpeed::Greater),
             just("P199KPH").map(|_| WindSpeed::Greater),
-            // Knots
-            just("//KT").map(|_| WindSpeed::Knots {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
-            group((
-                text::digits(10).exactly(2).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(2).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("KT"),
-            ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::Knots {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
-            // MPS
-            just("//MPS").map(|_| WindSpeed::MetresPerSecond {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
-            group((
-                text::digits(10).exactly(2).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(2).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("MPS"),
-            ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::MetresPerSecond {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
-            // KPH
-            just("//KPH").map(|_| WindSpeed::KilometresPerHour {
-                speed: Data::Unknown,
-                gusting: None,
-            }),
-            group((
-                text::digits(10).exactly(3).to_slice(),
-                choice((
-                    just("G//").map(|_| Some(Data::Unknown)),
-                    just("G")
-                        .then(text::digits(10).exactly(3).to_slice())
-                        .map(|(_, gust): (&str, &str)| Some(Data::Known(gust.parse().unwrap()))),
-                    empty().map(|()| None),
-                )),
-                just("KPH"),
-            ))
-            .map(|(spd, gusting, _): (&str, Option<Data<u32>>, &str)| {
-                WindSpeed::KilometresPerHour {
-                    speed: Data::Known(spd.parse().unwrap()),
-                    gusting,
-                }
-            }),
+            at_least(2, WindUnit::Knots),
+            at_least(2, WindUnit::MetresPerSecond),
+            at_least(3, WindUnit::KilometresPerHour),
+            speed_and_gust(2, "KT").map(|(speed, gusting)| WindSpeed::Knots { speed, gusting }),
+            speed_and_gust(2, "MPS")
+                .map(|(speed, gusting)| WindSpeed::MetresPerSecond { speed, gusting }),
+            speed_and_gust(3, "KPH")
+                .map(|(speed, gusting)| WindSpeed::KilometresPerHour { speed, gusting }),
         ))
     }
 }
 
 impl Display for WindSpeed {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            WindSpeed::Knots {
-                speed: Data::Known(speed),
-                gusting,
-            } => {
-                write!(f, "{speed:02}")?;
-                if let Some(gusting) = gusting {
-                    f.write_str("G")?;
-                    if let Data::Known(gusting) = gusting {
-                        write!(f, "{gusting:02}")?;
-                    } else {
-                        f.write_str("//")?;
-                    }
-                }
-                f.write_str("KT")
-            }
-            WindSpeed::Knots {
-                speed: Data::Unknown,
-                gusting: _,
-            } => f.write_str("//KT"),
-            WindSpeed::MetresPerSecond {
-                speed: Data::Known(speed),
-                gusting,
-            } => {
-                write!(f, "{speed:02}")?;
-                if let Some(gusting) = gusting {
-                    f.write_str("G")?;
-                    if let Data::Known(gusting) = gusting {
-                        write!(f, "{gusting:02}")?;
-                    } else {
-                        f.write_str("//")?;
-                    }
-                }
-                f.write_str("MPS")
+        let (speed, gusting) = match self {
+            WindSpeed::Knots { speed, gusting }
+            | WindSpeed::MetresPerSecond { speed, gusting }
+            | WindSpeed::KilometresPerHour { speed, gusting } => (speed, gusting),
+            WindSpeed::Greater => return f.write_str("P99KT"),
+            WindSpeed::AtLeast { speed, unit } => {
+                let width = if *unit == WindUnit::KilometresPerHour {
+                    3
+                } else {
+                    2
+                };
+                return write!(f, "P{speed:0width$}{unit}");
             }
-            WindSpeed::MetresPerSecond {
-                speed: Data::Unknown,
-                gusting: _,
-            } => f.write_str("//MPS"),
-            WindSpeed::KilometresPerHour {
-                speed: Data::Known(speed),
-                gusting,
-            } => {
-                write!(f, "{speed:02}")?;
-                if let Some(gusting) = gusting {
-                    f.write_str("G")?;
-                    if let Data::Known(gusting) = gusting {
-                        write!(f, "{gusting:02}")?;
-                    } else {
-                        f.write_str("//")?;
-                    }
-                }
-                f.write_str("KPH")
-            }
-            WindSpeed::KilometresPerHour {
-                speed: Data::Unknown,
-                gusting: _,
-            } => f.write_str("//KPH"),
-            WindSpeed::Greater => f.write_str("P99KT"),
+        };
+
+        let width = if matches!(self, WindSpeed::KilometresPerHour { .. }) {
+            3
+        } else {
+            2
+        };
+        let value = |v: &Data<u32>| match v {
+            Data::Known(v) => format!("{v:0width$}"),
+            Data::Unknown => "//".to_string(),
+        };
+
+        f.write_str(&value(speed))?;
+        if let Some(gusting) = gusting {
+            write!(f, "G{}", value(gusting))?;
+        }
+        self.unit().unwrap_or(WindUnit::Knots).fmt(f)
+    }
+}
+
+/// A unit of wind speed
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindUnit {
+    /// Nautical miles per hour (`KT`)
+    Knots,
+    /// Metres per second (`MPS`)
+    MetresPerSecond,
+    /// Kilometres per hour (`KPH`)
+    KilometresPerHour,
+}
+
+impl WindUnit {
+    /// Convert a speed given in this unit to knots.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "wind speeds are far below f32 precision limits"
+    )]
+    pub fn to_knots(self, speed: u32) -> f32 {
+        let speed = speed as f32;
+        match self {
+            WindUnit::Knots => speed,
+            WindUnit::MetresPerSecond => speed * 3600. / METRES_PER_NAUTICAL_MILE,
+            WindUnit::KilometresPerHour => speed * 1000. / METRES_PER_NAUTICAL_MILE,
         }
     }
 }
 
+impl Display for WindUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            WindUnit::Knots => "KT",
+            WindUnit::MetresPerSecond => "MPS",
+            WindUnit::KilometresPerHour => "KPH",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,6 +289,24 @@ mod tests {
             }
         );
         assert_eq!(WindSpeed::parse("P99KT").unwrap(), WindSpeed::Greater);
+        assert_eq!(
+            WindSpeed::parse("P49KT").unwrap(),
+            WindSpeed::AtLeast {
+                speed: 49,
+                unit: WindUnit::Knots
+            }
+        );
+    }
+
+    #[test]
+    fn knots() {
+        let knots = |s| WindSpeed::parse(s).unwrap().knots();
+        assert_eq!(knots("15G25KT"), Some(15.));
+        assert!((knots("10MPS").unwrap() - 19.438).abs() < 0.01);
+        assert!((knots("037KPH").unwrap() - 19.978).abs() < 0.01);
+        assert_eq!(knots("P49KT"), Some(49.));
+        assert_eq!(knots("//KT"), None);
+        assert_eq!(knots("P99KT"), None);
     }
 
     #[test]
@@ -298,4 +388,27 @@ mod tests {
         );
         assert_eq!(WindSpeed::parse("P199KPH").unwrap(), WindSpeed::Greater);
     }
+
+    #[test]
+    fn display_round_trip() {
+        for spd in [
+            "//KT",
+            "05KT",
+            "40G//KT",
+            "//G20MPS",
+            "040G060KPH",
+            "P99KT",
+            "P49KT",
+            "P120KPH",
+        ] {
+            assert_eq!(WindSpeed::parse(spd).unwrap().to_string(), spd);
+        }
+    }
+
+    #[test]
+    fn unit_to_knots() {
+        assert!((WindUnit::Knots.to_knots(10) - 10.).abs() < f32::EPSILON);
+        assert!((WindUnit::MetresPerSecond.to_knots(10) - 19.44).abs() < 0.01);
+        assert!((WindUnit::KilometresPerHour.to_knots(100) - 54.0).abs() < 0.01);
+    }
 }
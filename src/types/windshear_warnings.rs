@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use chumsky::prelude::*;
 
 use crate::{
@@ -5,43 +7,76 @@ use crate::{
     traits::Parsable,
 };
 
-/// A windshear warnings
+/// Windshear warnings
 #[derive(PartialEq, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindshearWarnings {
-    /// All runways are affected by windshear
-    AllRunways,
-    /// Only specific runways are affected by windshear
-    SpecificRunways(Vec<WindshearGroup>),
+    /// All runways are affected by windshear, `WS ALL RWY`
+    All,
+    /// Only the given runways are affected by windshear, e.g. `WS R24 WS R06`
+    Runways(Vec<String>),
 }
 
 impl Parsable for WindshearWarnings {
     fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
         choice((
-            just("WS ALL RWY").map(|_| WindshearWarnings::AllRunways),
-            WindshearGroup::parser()
-                .separated_by(some_whitespace())
-                .collect::<Vec<_>>()
-                .map(WindshearWarnings::SpecificRunways),
+            just("WS ALL RWY").map(|_| WindshearWarnings::All),
+            group((
+                just("WS"),
+                text::inline_whitespace().at_least(1),
+                runway_number(),
+            ))
+            .map(|(_, (), runway)| runway)
+            .separated_by(some_whitespace())
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(WindshearWarnings::Runways),
         ))
     }
 }
 
-/// A runway affected by windshear
-#[derive(PartialEq, Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct WindshearGroup {
-    /// The runway number
-    pub runway_number: String,
+impl Display for WindshearWarnings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindshearWarnings::All => f.write_str("WS ALL RWY"),
+            WindshearWarnings::Runways(runways) => {
+                for (i, runway) in runways.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "WS R{runway}")?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
-impl Parsable for WindshearGroup {
-    fn parser<'src>() -> impl Parser<'src, &'src str, Self, extra::Err<crate::MetarError<'src>>> {
-        group((
-            just("WS"),
-            text::inline_whitespace().at_least(1),
-            runway_number(),
-        ))
-        .map(|(_, (), runway_number)| WindshearGroup { runway_number })
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_runways() {
+        let ws = WindshearWarnings::parse("WS ALL RWY").unwrap();
+        assert_eq!(ws, WindshearWarnings::All);
+        assert_eq!(ws.to_string(), "WS ALL RWY");
+    }
+
+    #[test]
+    fn test_specific_runways() {
+        let ws = WindshearWarnings::parse("WS R24 WS R06").unwrap();
+        assert_eq!(
+            ws,
+            WindshearWarnings::Runways(vec!["24".to_string(), "06".to_string()])
+        );
+        assert_eq!(ws.to_string(), "WS R24 WS R06");
+
+        let ws = WindshearWarnings::parse("WS R16R WS R16L").unwrap();
+        assert_eq!(
+            ws,
+            WindshearWarnings::Runways(vec!["16R".to_string(), "16L".to_string()])
+        );
+        assert_eq!(ws.to_string(), "WS R16R WS R16L");
     }
 }
@@ -0,0 +1,29 @@
+use arbitrary::{Arbitrary, Unstructured};
+use metar::Metar;
+
+/// Generate deterministic pseudo-random bytes using xorshift.
+fn bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state.to_le_bytes()[0]
+        })
+        .collect()
+}
+
+#[test]
+fn test_round_trip() {
+    for seed in 0..2000 {
+        let data = bytes(seed, 512);
+        let mut u = Unstructured::new(&data);
+        let metar = Metar::arbitrary(&mut u).unwrap();
+        let metar_str = metar.to_string();
+        match Metar::parse(&metar_str) {
+            Ok(parsed) => assert_eq!(parsed, metar, "{metar_str}"),
+            Err(es) => panic!("failed to parse {metar_str}: {es:?}"),
+        }
+    }
+}
@@ -80,7 +80,7 @@ Feature: METAR Parsing
             | K2R2    | 22   | 10   | 55     | 020      | 3 kt     | none       | none       | 9 mi       | 10   | 10   | 30.30 inHg | K2R2 221055Z AUTO 02003KT 9SM CLR 10/10 A3030 RMK AO2 T01030103 $                                                                         |
             | HRYR    | 22   | 05   | 30     | unknown  | unknown  | unk unk    | none       | 5000 m     | 18   | 17   | 1022 hPa   | HRYR 220530Z /////KT ///V/// 5000 SCT005 BKN015 18/17 Q1022 NOSIG                                                                         |
             | ETHB    | 11   | 23   | 50     | 270      | 4 kt     | none       | none       | 3500 m     | 2    | 1    | 1031 hPa   | ETHB 112350Z 27004KT 3500 -DZ BR OVC007 02/01 Q1031 YLO                                                                                   |
-            | LSZL    | 11   | 23   | 50     | 000      | 0 kt     | none       | none       | unknown    | -2   | -2   | 1027 hPa   | METAR LSZL 112350Z AUTO 00000KT 9999NDV NCD M02/M02 Q1027                                                                                 |
+            | LSZL    | 11   | 23   | 50     | 000      | 0 kt     | none       | none       | 9999 m     | -2   | -2   | 1027 hPa   | METAR LSZL 112350Z AUTO 00000KT 9999NDV NCD M02/M02 Q1027                                                                                 |
             | ESUT    | 11   | 23   | 50     | 310      | 15 kt    | none       | 26 kt      | unknown    | -4   | -6   | 1022 hPa   | ESUT 112350Z AUTO 31015G26KT //// R33///// // BKN022/// OVC031/// M04/M06 Q1022                                                           |
             | LTFJ    | 11   | 23   | 50     | 100      | 3 kt     | 070 160    | none       | 3200 m     | 8    | 7    | 1022 hPa   | LTFJ 112350Z 10003KT 070V160 3200 0500NE R06L/P1500N R24R/0600D R06R/P1500D R24L/0900N BCFG FEW001 BKN030 08/07 Q1022 TEMPO 0500 FG VV001 |
             | LTBD    | 11   | 23   | 50     | 000      | 0 kt     | none       | none       | 1200 m     | 7    | 7    | 1022 hPa   | LTBD 112350Z 00000KT 1200 R09/0500N R27////// BCFG NSC 07/07 Q1022                                                                        |
@@ -105,3 +105,5 @@ Feature: METAR Parsing
         | EGPC 211650Z 33026G37KT 1 FEW021 12/7 Q1026                   |
         | EGPC 211650Z 33026G37KT 100SM FEW021 1/70 Q1026               |
         | EGPC 211650Z 3026KT 9999 FEW021 12/7 Q1026                    |
+        | EGLL 010000Z 180V240 9999 FEW030 16/14 Q1006                  |
+        | EGLL 010000Z 21010KT VRBV240 9999 FEW030 16/14 Q1006          |
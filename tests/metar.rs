@@ -1,4 +1,10 @@
-use metar::Metar;
+use metar::{
+    CeilingCategory, CloudCover, CloudType, Clouds, ColourCode, CompassDirection, Data,
+    ErrorVariant, FlightCategory, FormatOptions, Kind, Metar, MetarError, MetarParseError,
+    OwnedMetarError, ParseOptions, Pressure, PressureUnit, RunwayContamination, RunwayFriction,
+    Trend, Visibility, VisibilityUnit, Weather, WeatherCondition, WeatherIntensity, Wind,
+    WindDirection, WindSpeed, WindUnit, WindshearWarnings,
+};
 
 #[test]
 fn test_display() {
@@ -24,6 +30,11 @@ fn test_display() {
     let metar = Metar::parse(metar_str).unwrap();
     assert_eq!(metar_str, metar.to_string());
 
+    let metar_str = "EKVG 232250Z 31006KT 0100 FG OVC000 09/09 Q0995";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar_str, metar.to_string());
+    assert_eq!(metar.ceiling(), Some(0));
+
     let metar_str = "BGGH 232250Z 21007KT 0700 R22/P2000N -RA FG FEW002 BKN004 OVC006 05/05 Q1005";
     let metar = Metar::parse(metar_str).unwrap();
     assert_eq!(metar_str, metar.to_string());
@@ -76,3 +87,1142 @@ fn test_display() {
     let metar = Metar::parse(metar_str).unwrap();
     assert_eq!(metar_str, metar.to_string());
 }
+
+#[test]
+fn test_with_precise_temperatures() {
+    let metar = Metar::parse("KJFK 121251Z 24016G24KT 10SM FEW250 22/18 A2996 RMK AO2 T02210183")
+        .unwrap()
+        .with_precise_temperatures();
+    assert_eq!(metar.temperature, Data::Known(22.1));
+    assert_eq!(metar.dewpoint, Data::Known(18.3));
+}
+
+#[test]
+fn test_explicitly_positive_temperature() {
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 +24/+18 Q1006").unwrap();
+    assert_eq!(metar.temperature, Data::Known(24.));
+    assert_eq!(metar.dewpoint, Data::Known(18.));
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 +02/M01 Q1006").unwrap();
+    assert_eq!(metar.temperature, Data::Known(2.));
+    assert_eq!(metar.dewpoint, Data::Known(-1.));
+}
+
+#[test]
+fn test_effective_temperature() {
+    let metar =
+        Metar::parse("KJFK 121251Z 24016G24KT 10SM FEW250 ///// A2996 RMK AO2 T01230089").unwrap();
+    assert_eq!(metar.temperature, Data::Unknown);
+    assert_eq!(metar.effective_temperature(), Data::Known(12.3));
+
+    // The body temperature is preferred
+    let metar =
+        Metar::parse("KJFK 121251Z 24016G24KT 10SM FEW250 12/09 A2996 RMK AO2 T01230089").unwrap();
+    assert_eq!(metar.effective_temperature(), Data::Known(12.));
+
+    let metar = Metar::parse("KJFK 121251Z 24016G24KT 10SM FEW250 ///// A2996 RMK AO2").unwrap();
+    assert_eq!(metar.effective_temperature(), Data::Unknown);
+}
+
+#[test]
+fn test_unknown_weather() {
+    let metar_str = "EGLL 010000Z AUTO 24010KT 9999 // FEW030 16/14 Q1006";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.weather, Data::Unknown);
+    assert_eq!(metar.cloud_layers.len(), 1);
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar_str = "EGLL 010000Z AUTO 24010KT 9999 //RA FEW030 16/14 Q1006";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(
+        metar.weather,
+        Data::Known(vec![Weather {
+            intensity: WeatherIntensity::Unknown,
+            conditions: vec![WeatherCondition::Rain],
+        }])
+    );
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_all_weather() {
+    let metar = Metar::parse(
+        "EDDM 231420Z AUTO 27008KT 9999 -TSRA BR SCT///CB 24/18 Q1013 RESHRA TEMPO 28020G35KT 3500 TSRA",
+    )
+    .unwrap();
+    let wx = metar.all_weather().collect::<Vec<_>>();
+    assert_eq!(wx.len(), 4);
+    assert_eq!(wx[2].intensity, WeatherIntensity::Recent);
+    assert_eq!(
+        wx[2].conditions,
+        vec![WeatherCondition::Showers, WeatherCondition::Rain]
+    );
+    assert_eq!(
+        wx[3].conditions,
+        vec![WeatherCondition::Thunderstorm, WeatherCondition::Rain]
+    );
+}
+
+#[test]
+fn test_clouds_summary() {
+    let metar =
+        Metar::parse("EGGD 071320Z 19009KT 9999 -SHRA SCT020 FEW015TCU BKN040 SCT020 17/15 Q1011")
+            .unwrap();
+    assert_eq!(
+        metar.clouds_summary(),
+        vec![
+            (CloudCover::Few, 15, CloudType::ToweringCumulus),
+            (CloudCover::Scattered, 20, CloudType::Normal),
+            (CloudCover::Broken, 40, CloudType::Normal),
+        ]
+    );
+
+    let metar = Metar::parse("EDSB 242150Z AUTO 18003KT 9999 NCD 20/14 Q1015").unwrap();
+    assert!(metar.clouds_summary().is_empty());
+}
+
+#[test]
+fn test_peak_wind_unit() {
+    let metar = Metar::parse("UUDD 061930Z 36012MPS 330V030 CAVOK 16/08 Q1016 RMK PK WND 30025/35")
+        .unwrap();
+    let peak_wind = metar.decoded_remarks().unwrap().peak_wind.unwrap();
+    assert_eq!(peak_wind.speed, 25);
+    assert_eq!(peak_wind.unit, WindUnit::MetresPerSecond);
+}
+
+#[test]
+fn test_gust_spread() {
+    let metar = Metar::parse("EGHI 282120Z 25015G28KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.gust_spread(), Some(13));
+
+    let metar = Metar::parse("EGHI 282120Z 25015KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.gust_spread(), None);
+
+    let metar = Metar::parse("EGHI 282120Z 25028G15KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.gust_spread(), None);
+}
+
+#[test]
+fn test_is_gusty() {
+    let metar = Metar::parse("EGHI 282120Z 25015G25KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert!(metar.is_gusty(9));
+    assert!(metar.is_gusty(10));
+    assert!(!metar.is_gusty(11));
+
+    // 6 m/s is about 11.7 kt
+    let metar = Metar::parse("UUEE 282120Z 25008G14MPS 9999 FEW030 16/14 Q1006").unwrap();
+    assert!(metar.is_gusty(10));
+    assert!(!metar.is_gusty(12));
+
+    let metar = Metar::parse("EGHI 282120Z 25015KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert!(!metar.is_gusty(0));
+
+    let metar = Metar::parse("EGHI 282120Z 00000KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert!(!metar.is_gusty(0));
+}
+
+#[test]
+fn test_nosig_before_remarks() {
+    let metar_str = "EGLL 281350Z 24010KT 9999 FEW040 18/10 Q1013 NOSIG RMK AO2 SLP201";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.trends, vec![Trend::NoSignificantChanges]);
+    assert_eq!(metar.remarks.as_deref(), Some("AO2 SLP201"));
+    assert_eq!(metar_str, metar.to_string());
+}
+
+#[test]
+fn test_wind_varying_without_wind() {
+    let es = Metar::parse("EGLL 010000Z 180V240 9999 FEW030 16/14 Q1006").unwrap_err();
+    assert_eq!(es.len(), 1);
+    assert_eq!(es[0].variant, ErrorVariant::WindVaryingWithoutWind);
+    assert_eq!((es[0].start, es[0].end), (13, 20));
+    assert_eq!(es[0].line_col(), (1, 14));
+}
+
+#[test]
+fn test_error_line_col() {
+    let input = "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006\nEGLL 010030Z 180V240 9999";
+    let line_start = input.find('\n').unwrap() + 1;
+    let es = Metar::parse(&input[line_start..]).unwrap_err();
+    let error = MetarError {
+        string: input,
+        start: line_start + es[0].start,
+        end: line_start + es[0].end,
+        variant: es[0].variant.clone(),
+    };
+    assert_eq!(error.line_col(), (2, 14));
+    assert_eq!(error.into_owned().line_col(), (2, 14));
+}
+
+#[test]
+fn test_trend_colour_code() {
+    let metar_str = "ETHB 112350Z 27004KT 3500 -DZ BR OVC007 02/01 Q1031 YLO BECMG GRN";
+    let metar = Metar::parse(metar_str).unwrap();
+    let Trend::Becoming(cond) = &metar.trends[0] else {
+        panic!("expected a BECMG trend");
+    };
+    assert_eq!(cond.colour_code, Some(Data::Known(ColourCode::Green)));
+    assert!(cond.weather.is_empty());
+    assert_eq!(metar_str, metar.to_string());
+
+    let metar_str = "ETHB 112350Z 27004KT 3500 -DZ BR OVC007 02/01 Q1031 YLO TEMPO 1200 RED";
+    let metar = Metar::parse(metar_str).unwrap();
+    let Trend::Temporarily(cond) = &metar.trends[0] else {
+        panic!("expected a TEMPO trend");
+    };
+    assert_eq!(cond.colour_code, Some(Data::Known(ColourCode::Red)));
+    assert_eq!(metar_str, metar.to_string());
+}
+
+#[test]
+fn test_remark_tokens() {
+    let metar =
+        Metar::parse("KJFK 121251Z 24016KT 10SM FEW250 20/15 A2996 RMK AO2 SLP201 T02000150")
+            .unwrap();
+    assert_eq!(metar.remark_tokens(), vec!["AO2", "SLP201", "T02000150"]);
+
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 SCT006 16/14 Q1006").unwrap();
+    assert!(metar.remark_tokens().is_empty());
+}
+
+#[test]
+fn test_all_slashes_unknown() {
+    let metar = Metar::parse("ETSB 032220Z AUTO /////KT //// // ////// ///// Q//// ///").unwrap();
+    assert_eq!(
+        metar.wind,
+        Wind::Present {
+            dir: WindDirection::Heading(Data::Unknown),
+            speed: WindSpeed::Knots {
+                speed: Data::Unknown,
+                gusting: None,
+            },
+            varying: None,
+        }
+    );
+    assert_eq!(metar.visibility, Data::Unknown);
+    assert_eq!(metar.weather, Data::Unknown);
+    assert_eq!(metar.temperature, Data::Unknown);
+    assert_eq!(metar.dewpoint, Data::Unknown);
+    assert_eq!(metar.pressure, Pressure::Hectopascals(Data::Unknown));
+    assert_eq!(metar.colour_code, Some(Data::Unknown));
+
+    let metar =
+        Metar::parse("ESUT 112350Z AUTO 31015G26KT //// R33///// // BKN022/// M04/M06 Q1022")
+            .unwrap();
+    assert_eq!(metar.rvr[0].value, Data::Unknown);
+}
+
+#[test]
+fn test_observation_complete() {
+    let metar = Metar::parse("ETSB 032220Z AUTO /////KT //// // ////// ///// Q//// ///").unwrap();
+    assert!(metar.observation_complete() < 0.2);
+
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006").unwrap();
+    assert!((metar.observation_complete() - 1.).abs() < f32::EPSILON);
+
+    let metar = Metar::parse("EGHI 282120Z 19015KT //// RA SCT006 16/// Q1006").unwrap();
+    assert!((metar.observation_complete() - 0.75).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_needs_deicing() {
+    let metar = Metar::parse("EGLL 010000Z 24010KT 3000 FZRA OVC008 M02/M03 Q1006").unwrap();
+    assert!(metar.needs_deicing());
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 6000 -RA OVC008 05/03 Q1006").unwrap();
+    assert!(!metar.needs_deicing());
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 2000 -SN OVC008 M01/M03 Q1006").unwrap();
+    assert!(metar.needs_deicing());
+
+    let metar =
+        Metar::parse("KORD 010000Z 24010KT 6000 OVC008 01/M01 A2992 RMK AO2 I1002").unwrap();
+    assert!(metar.needs_deicing());
+}
+
+#[test]
+fn test_clear_sky_kinds() {
+    for (metar_str, clouds) in [
+        (
+            "CYWG 190500Z 16014KT 10SM SKC 19/11 A2959",
+            Clouds::SkyClear,
+        ),
+        (
+            "KEEN 061356Z AUTO 00000KT 10SM CLR 06/M03 A3029",
+            Clouds::Clear,
+        ),
+        (
+            "EDDM 061356Z AUTO 00000KT 9999 NCD 06/M03 Q1012",
+            Clouds::NoCloudDetected,
+        ),
+        ("KPHF 191054Z 00000KT 5SM BR CLR 11/11 A3003", Clouds::Clear),
+    ] {
+        let metar = Metar::parse(metar_str).unwrap();
+        assert_eq!(metar.clouds, clouds);
+        assert!(metar.cloud_layers.is_empty());
+        assert_eq!(metar.to_string(), metar_str);
+    }
+}
+
+#[test]
+fn test_unknown_dewpoint() {
+    let metar_str = "EGLL 010000Z 24010KT 9999 FEW030 24/// Q1006";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.temperature, Data::Known(24.));
+    assert_eq!(metar.dewpoint, Data::Unknown);
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 24/ Q1006").unwrap();
+    assert_eq!(metar.temperature, Data::Known(24.));
+    assert_eq!(metar.dewpoint, Data::Unknown);
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 M02/ Q1006").unwrap();
+    assert_eq!(metar.temperature, Data::Known(-2.));
+    assert_eq!(metar.dewpoint, Data::Unknown);
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z 24010KT 9999 FEW030 M02/// Q1006"
+    );
+}
+
+#[test]
+fn test_summary() {
+    let metar =
+        Metar::parse("EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006").unwrap();
+    assert_eq!(
+        metar.summary(),
+        "Wind 190° at 15 kt, varying 140°-220°, visibility 6 km, weather RA, \
+         scattered cloud at 600 ft, broken cloud at 900 ft, 16°C/14°C, QNH 1006 hPa."
+    );
+
+    let metar = Metar::parse("ETSB 032220Z AUTO /////KT //// // ////// ///// Q//// ///").unwrap();
+    let summary = metar.summary();
+    assert!(summary.contains("Wind from unknown direction at unknown speed"));
+    assert!(summary.contains("visibility unknown"));
+    assert!(summary.contains("unknown/unknown"));
+    assert!(summary.contains("pressure unknown"));
+
+    let metar = Metar::parse("KEEN 061356Z AUTO 00000KT 10SM CLR M06/M13 A3029").unwrap();
+    assert_eq!(
+        metar.summary(),
+        "Wind 000° at 0 kt, visibility 10 SM, no cloud below 12000 ft, -6°C/-13°C, \
+         altimeter 30.29 inHg."
+    );
+}
+
+#[test]
+fn test_runway_cleared() {
+    let metar = Metar::parse("UUDD 151230Z 26007MPS CAVOK 23/09 Q1008 R88/CLRD// NOSIG").unwrap();
+    assert_eq!(metar.runway_conditions.len(), 1);
+    assert_eq!(metar.runway_conditions[0].runway_number, "88");
+    assert_eq!(
+        metar.runway_conditions[0].contamination,
+        RunwayContamination::Cleared
+    );
+    assert_eq!(metar.runway_conditions[0].friction, RunwayFriction::Unknown);
+    assert_eq!(metar.trends, vec![Trend::NoSignificantChanges]);
+}
+
+#[test]
+fn test_variable_gusting_wind() {
+    let metar_str = "KOKC 011955Z VRB15G25KT 10SM TSRA BKN040CB 28/19 A2992";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(
+        metar.wind,
+        Wind::Present {
+            dir: WindDirection::Variable,
+            speed: WindSpeed::Knots {
+                speed: Data::Known(15),
+                gusting: Some(Data::Known(25)),
+            },
+            varying: None,
+        }
+    );
+    assert!(metar.wind_is_variable());
+    assert!(metar.wind_is_gusting());
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_metar_outlives_input() {
+    struct Station {
+        latest: Metar,
+    }
+
+    fn assert_static<T: 'static>(_: &T) {}
+
+    let station = {
+        let input = String::from("EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006");
+        Station {
+            latest: Metar::parse(&input).unwrap(),
+        }
+    };
+    assert_static(&station.latest);
+    assert_eq!(station.latest.station, "EGHI");
+}
+
+#[test]
+fn test_fractional_statute_miles() {
+    for metar_str in [
+        "KJFK 011951Z 04012KT 3/4SM BR OVC004 12/11 A2990",
+        "KJFK 011951Z 04012KT 1 3/4SM BR OVC004 12/11 A2990",
+    ] {
+        let metar = Metar::parse(metar_str).unwrap();
+        assert_eq!(metar.to_string(), metar_str);
+    }
+}
+
+#[test]
+fn test_is_automated() {
+    let metar = Metar::parse("EDDM 222020Z AUTO VRB01KT CAVOK 20/13 Q1017").unwrap();
+    assert!(metar.is_automated());
+
+    let metar = Metar::parse("KORD 121856Z 09014KT 10SM CLR 32/23 A2992 RMK AO2 SLP131").unwrap();
+    assert!(metar.is_automated());
+
+    let metar =
+        Metar::parse("EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006").unwrap();
+    assert!(!metar.is_automated());
+}
+
+#[test]
+fn test_unknown_cloud_density() {
+    let metar_str = "ESUT 112350Z AUTO 31015G26KT 9999 ///015 ///030CB M04/M06 Q1022";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.cloud_layers.len(), 2);
+    assert_eq!(metar.cloud_layers[0].density, Data::Unknown);
+    assert_eq!(metar.cloud_layers[0].height, Data::Known(15));
+    assert_eq!(metar.cloud_layers[1].height, Data::Known(30));
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_non_ascii_remarks() {
+    let metar_str = "LFPG 010000Z 24010KT 9999 FEW030 16/14 Q1006 RMK PRÉVISION ÉTÉ";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.remarks.as_deref(), Some("PRÉVISION ÉTÉ"));
+    assert_eq!(metar.remark_tokens(), vec!["PRÉVISION", "ÉTÉ"]);
+    assert_eq!(
+        metar.decoded_remarks().unwrap().unparsed,
+        vec!["PRÉVISION", "ÉTÉ"]
+    );
+    assert_eq!(metar.to_string(), metar_str);
+
+    // Errors after a multibyte character must not split it
+    let es = Metar::parse("LFPG 010000Z 24010KT 9999 FEW030 16/14 Q1006 É É").unwrap_err();
+    assert_eq!(es[0].line_col(), (1, 46));
+    assert!(!es[0].to_string().is_empty());
+    let error = MetarError {
+        start: es[0].start + 1,
+        ..es[0].clone()
+    };
+    assert_eq!(error.line_col(), (1, 47));
+}
+
+#[test]
+fn test_all_clouds() {
+    let metar = Metar::parse("EDDM 061356Z AUTO 00000KT 9999 NCD 06/M03 Q1012").unwrap();
+    assert!(metar.all_clouds().is_empty());
+
+    let metar = Metar::parse("EDDM 061356Z AUTO 00000KT CAVOK 06/M03 Q1012").unwrap();
+    assert!(metar.all_clouds().is_empty());
+
+    let metar =
+        Metar::parse("EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006").unwrap();
+    assert_eq!(metar.all_clouds(), metar.cloud_layers);
+    assert_eq!(metar.all_clouds().len(), 2);
+}
+
+#[test]
+fn test_auto_before_station() {
+    let metar = Metar::parse("METAR AUTO EGLL 282120Z 19015KT 9999 NCD 16/14 Q1006").unwrap();
+    assert_eq!(metar.station, "EGLL");
+    assert_eq!(metar.kind, Kind::Automatic);
+    assert!(metar.is_automated());
+
+    let displayed = metar.to_string();
+    assert_eq!(displayed, "EGLL 282120Z AUTO 19015KT 9999 NCD 16/14 Q1006");
+    let reparsed = Metar::parse(&displayed).unwrap();
+    assert_eq!(reparsed, metar);
+    assert_eq!(reparsed.to_string(), displayed);
+
+    let metar = Metar::parse("AUTO EGLL 282120Z 19015KT 9999 NCD 16/14 Q1006").unwrap();
+    assert_eq!(metar.kind, Kind::Automatic);
+    assert_eq!(metar.to_string(), displayed);
+}
+
+#[test]
+fn test_wind_unit() {
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.wind_unit(), Some(WindUnit::Knots));
+
+    let metar = Metar::parse("UUDD 151230Z 26007MPS CAVOK 23/09 Q1008").unwrap();
+    assert_eq!(metar.wind_unit(), Some(WindUnit::MetresPerSecond));
+
+    let metar = Metar::parse("EDDM 222020Z AUTO 00000KT CAVOK 20/13 Q1017").unwrap();
+    assert_eq!(metar.wind_unit(), Some(WindUnit::Knots));
+
+    let metar = Metar::parse("EGLL 010000Z CALM 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.wind_unit(), None);
+}
+
+#[test]
+fn test_trend_cloud_layers() {
+    let metar_str =
+        "EGLL 010000Z 24010KT 9999 SCT030 16/14 Q1006 TEMPO FEW010 BKN020 BECMG SCT015 BKN030";
+    let metar = Metar::parse(metar_str).unwrap();
+    let [Trend::Temporarily(tempo), Trend::Becoming(becmg)] = metar.trends.as_slice() else {
+        panic!("unexpected trends: {:?}", metar.trends);
+    };
+    assert_eq!(
+        tempo
+            .cloud
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["FEW010", "BKN020"]
+    );
+    assert_eq!(
+        becmg
+            .cloud
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["SCT015", "BKN030"]
+    );
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_multiple_recent_weather() {
+    let metar_str = "EDDM 231520Z AUTO 25012KT CAVOK 24/19 Q1012 RERA RESN RE//";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(
+        metar.recent_weather,
+        vec![
+            Data::Known(vec![WeatherCondition::Rain]),
+            Data::Known(vec![WeatherCondition::Snow]),
+            Data::Unknown,
+        ]
+    );
+    assert_eq!(metar_str, metar.to_string());
+}
+
+#[test]
+fn test_parse_report() {
+    fn assert_send_sync<T: Send + Sync + std::error::Error + 'static>() {}
+    assert_send_sync::<MetarParseError>();
+
+    fn parse(data: &str) -> Result<Metar, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Metar::parse_report(data)?)
+    }
+
+    let metar = parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006").unwrap();
+    assert_eq!(metar.station, "EGHI");
+
+    let err =
+        Metar::parse_report("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006 XYZ").unwrap_err();
+    assert!(!err.errors.is_empty());
+    assert!(!err.to_string().is_empty());
+    assert!(parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006 XYZ").is_err());
+}
+
+#[test]
+fn test_parse_report_multiple_errors() {
+    let input = "EGLL 010000Z 180V240 9999 FEW030 16/14 Q1006";
+    let error = |start, end, variant| OwnedMetarError {
+        string: input.to_string(),
+        start,
+        end,
+        variant,
+    };
+    let first = error(13, 20, ErrorVariant::WindVaryingWithoutWind);
+    let second = error(21, 25, ErrorVariant::MissingGustSeparator);
+    let err = MetarParseError {
+        errors: vec![first.clone(), second.clone()],
+    };
+
+    let message = err.to_string();
+    assert_eq!(message, format!("{first}\n{second}"));
+    assert!(message.contains("\n\n"));
+    assert!(message.contains("wind variation given without a wind"));
+    assert!(message.contains("wind gust given without a \"G\" separator"));
+}
+
+#[test]
+fn test_remarks_without_rmk() {
+    let metar_str = "EGHI 282120Z 19015KT 6000 SCT006 16/14 Q1006 FCST NEXT 12HR";
+    assert!(Metar::parse(metar_str).is_err());
+
+    let options = ParseOptions {
+        remarks_without_rmk: true,
+        ..Default::default()
+    };
+    let metar = Metar::parse_with(metar_str, options).unwrap();
+    assert_eq!(metar.pressure, Pressure::Hectopascals(Data::Known(1006)));
+    assert_eq!(metar.remarks.as_deref(), Some("FCST NEXT 12HR"));
+
+    let metar = Metar::parse_with(
+        "EGHI 282120Z 19015KT 6000 SCT006 16/14 Q1006 RMK AO2",
+        options,
+    )
+    .unwrap();
+    assert_eq!(metar.remarks.as_deref(), Some("AO2"));
+}
+
+#[test]
+fn test_invalid_weather_intensity() {
+    let metar = Metar::parse("EGLL 010000Z 24010KT 0300 +FG OVC002 16/14 Q1006").unwrap();
+    assert_eq!(
+        metar.weather,
+        Data::Known(vec![Weather {
+            intensity: WeatherIntensity::Heavy,
+            conditions: vec![WeatherCondition::Fog],
+        }])
+    );
+    assert_eq!(metar.warnings, vec![ErrorVariant::InvalidWeatherIntensity]);
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z 24010KT 0300 +FG OVC002 16/14 Q1006"
+    );
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 4000 VCSH +TSRA BKN010CB 16/14 Q1006 TEMPO -BR")
+        .unwrap();
+    assert_eq!(metar.warnings, vec![ErrorVariant::InvalidWeatherIntensity]);
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 4000 VCSH +TSRA BKN010CB 16/14 Q1006").unwrap();
+    assert!(metar.warnings.is_empty());
+}
+
+#[test]
+fn test_gust_without_separator() {
+    let metar_str = "EGLL 282120Z 25015928KT 9999 FEW030 16/14 Q1006";
+    let es = Metar::parse(metar_str).unwrap_err();
+    assert_eq!(es.len(), 1);
+    assert_eq!(es[0].variant, ErrorVariant::MissingGustSeparator);
+    assert_eq!((es[0].start, es[0].end), (16, 23));
+
+    let options = ParseOptions {
+        gust_without_separator: true,
+        ..Default::default()
+    };
+    let metar = Metar::parse_with(metar_str, options).unwrap();
+    assert_eq!(
+        metar.wind,
+        Wind::Present {
+            dir: WindDirection::Heading(Data::Known(250)),
+            speed: WindSpeed::Knots {
+                speed: Data::Known(15),
+                gusting: Some(Data::Known(28)),
+            },
+            varying: None,
+        }
+    );
+    assert_eq!(metar.warnings, vec![ErrorVariant::MissingGustSeparator]);
+
+    // Well-formed wind is unaffected
+    let metar =
+        Metar::parse_with("EGLL 282120Z 25015G28KT 9999 FEW030 16/14 Q1006", options).unwrap();
+    assert!(metar.warnings.is_empty());
+
+    // A gust slower than the wind is not plausible
+    assert!(Metar::parse_with("EGLL 282120Z 25028915KT 9999 FEW030 16/14 Q1006", options).is_err());
+}
+
+#[test]
+fn test_format_with() {
+    let metar =
+        Metar::parse("EGHI 282120Z 19015KT 4800 RA BKN009 16/14 Q1013 TEMPO 1600 RMK AO2").unwrap();
+    assert_eq!(
+        metar.format_with(&FormatOptions::default()),
+        metar.to_string()
+    );
+
+    let us = FormatOptions {
+        visibility_unit: Some(VisibilityUnit::StatuteMiles),
+        pressure_unit: Some(PressureUnit::InchesOfMercury),
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        metar.format_with(&us),
+        "EGHI 282120Z 19015KT 3SM RA BKN009 16/14 A2991 TEMPO 1SM RMK AO2"
+    );
+
+    let metar =
+        Metar::parse("KJFK 121251Z 24016KT 3SM BR FEW250 22/18 A2992 NOSIG RMK AO2").unwrap();
+    let metric = FormatOptions {
+        visibility_unit: Some(VisibilityUnit::Metres),
+        pressure_unit: Some(PressureUnit::Hectopascals),
+        include_trends: false,
+        include_remarks: false,
+        terminator: false,
+    };
+    assert_eq!(
+        metar.format_with(&metric),
+        "KJFK 121251Z 24016KT 4828 BR FEW250 22/18 Q1013"
+    );
+}
+
+#[test]
+fn test_maintenance_indicator() {
+    let metar_str = "KJFK 121251Z 24016KT 10SM FEW250 22/18 A2996 RMK AO2 $";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert!(metar.maintenance_indicator);
+    assert_eq!(metar.remarks.as_deref(), Some("AO2"));
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar_str = "KJFK 121251Z 24016KT 10SM FEW250 22/18 A2996 $";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert!(metar.maintenance_indicator);
+    assert_eq!(metar.remarks, None);
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar = Metar::parse("KJFK 121251Z 24016KT 10SM FEW250 22/18 A2996 RMK AO2").unwrap();
+    assert!(!metar.maintenance_indicator);
+}
+
+#[test]
+fn test_to_string_with_equals() {
+    let metar_str = "EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006 NOSIG";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.to_string_with_equals(), format!("{metar_str}="));
+
+    // Parsing a terminated report doesn't duplicate the terminator
+    let metar = Metar::parse(&format!("{metar_str}=")).unwrap();
+    assert_eq!(metar.to_string_with_equals(), format!("{metar_str}="));
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_raw() {
+    let metar_str = "EGHI 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006";
+    let metar = Metar::parse(&format!("{metar_str}  ")).unwrap();
+    assert_eq!(metar.raw.as_deref(), Some(metar_str));
+
+    let mut other = metar.clone();
+    other.raw = None;
+    assert_eq!(metar, other);
+}
+
+#[test]
+fn test_additional_pressure() {
+    let metar_str = "KJFK 121251Z 24016KT 10SM FEW250 22/18 Q1013 A2992 RMK AO2";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.pressure, Pressure::Hectopascals(Data::Known(1013)));
+    assert_eq!(
+        metar.additional_pressure,
+        Some(Pressure::InchesOfMercury(Data::Known(29.92)))
+    );
+    assert_eq!(metar_str, metar.to_string());
+}
+
+#[test]
+fn test_significant_weather() {
+    let metar =
+        Metar::parse("EDDM 231420Z AUTO 27008KT 4000 -RA +TSRA BR SCT///CB 24/18 Q1013").unwrap();
+    let significant = metar.significant_weather();
+    assert_eq!(significant.len(), 1);
+    assert_eq!(significant[0].intensity, WeatherIntensity::Heavy);
+    assert_eq!(
+        significant[0].conditions,
+        vec![WeatherCondition::Thunderstorm, WeatherCondition::Rain]
+    );
+
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 -RA SCT006 16/14 Q1006").unwrap();
+    assert!(metar.significant_weather().is_empty());
+}
+
+#[test]
+fn test_trend_statute_miles() {
+    let metar_str = "KJFK 121251Z 24016KT 10SM FEW250 22/18 A2996 TEMPO 2SM BR";
+    let metar = Metar::parse(metar_str).unwrap();
+    let Trend::Temporarily(cond) = &metar.trends[0] else {
+        panic!("expected a TEMPO trend");
+    };
+    assert_eq!(cond.visibility, Some(Visibility::StatuteMiles(2.)));
+    assert_eq!(
+        cond.weather,
+        vec![Weather {
+            intensity: WeatherIntensity::Moderate,
+            conditions: vec![WeatherCondition::Mist],
+        }]
+    );
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_no_directional_variation() {
+    let metar_str = "LSZL 112350Z AUTO 00000KT 6000NDV NCD M02/M02 Q1027";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(metar.visibility, Data::Known(Visibility::Metres(6000)));
+    assert!(metar.no_directional_variation);
+    assert!(metar.reduced_directional_visibility.is_empty());
+    assert_eq!(metar.to_string(), metar_str);
+
+    let metar = Metar::parse("LSZL 112350Z AUTO 00000KT 6000 NCD M02/M02 Q1027").unwrap();
+    assert!(!metar.no_directional_variation);
+}
+
+#[test]
+fn test_visibility_in_kilometres() {
+    let metar = Metar::parse("EGLL 010000Z 27010KT 10KM FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.visibility, Data::Known(Visibility::Metres(10000)));
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z 27010KT 9999 FEW030 16/14 Q1006"
+    );
+
+    // 10km or more is written as 9999, which parses back to an equivalent
+    // visibility
+    let reparsed = Metar::parse(&metar.to_string()).unwrap();
+    assert_eq!(reparsed.visibility, Data::Known(Visibility::Metres(9999)));
+    assert!(reparsed.visibility.unwrap().is_ten_km_or_more());
+    assert_eq!(reparsed.to_string(), metar.to_string());
+}
+
+#[test]
+fn test_more_than_six_statute_miles() {
+    let metar_str = "KJFK 121251Z 24016G24KT P6SM FEW250 28/23 A2996";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(
+        metar.visibility,
+        Data::Known(Visibility::MoreThanStatuteMiles(6.))
+    );
+    assert!(metar.visibility.unwrap().is_unlimited());
+    assert_eq!(metar.to_string(), metar_str);
+}
+
+#[test]
+fn test_resolved_cavok() {
+    let cavok = Metar::parse("EGLL 010000Z 24010KT CAVOK 16/14 Q1006").unwrap();
+    let explicit = Metar::parse("EGLL 010000Z 24010KT 10KM NSC 16/14 Q1006").unwrap();
+    assert_eq!(cavok.resolved_visibility(), explicit.resolved_visibility());
+    assert_eq!(cavok.resolved_clouds(), explicit.resolved_clouds());
+    assert_eq!(
+        cavok.resolved_visibility(),
+        Data::Known(Visibility::Metres(10_000))
+    );
+    assert_eq!(cavok.resolved_visibility().unwrap().to_string(), "9999");
+    assert_eq!(cavok.resolved_clouds(), Clouds::NoSignificantCloud);
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 6000 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.resolved_visibility(), metar.visibility);
+    assert_eq!(metar.resolved_clouds(), Clouds::CloudLayers);
+}
+
+#[test]
+fn test_visibility_is_at_least() {
+    let at_least = |vis: &str| {
+        Metar::parse(&format!("EGLL 010000Z 24010KT {vis} FEW030 16/14 Q1006"))
+            .unwrap()
+            .visibility_is_at_least(5000)
+    };
+    assert_eq!(at_least("9999"), Some(true));
+    assert_eq!(at_least("5000"), Some(true));
+    assert_eq!(at_least("4999"), Some(false));
+    assert_eq!(at_least("3SM"), Some(false));
+    assert_eq!(at_least("4SM"), Some(true));
+    assert_eq!(at_least("////"), None);
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT CAVOK 16/14 Q1006").unwrap();
+    assert_eq!(metar.visibility_is_at_least(10_000), Some(true));
+}
+
+#[test]
+fn test_ceiling_and_flight_category() {
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 RA SCT006 BKN009 16/14 Q1006").unwrap();
+    assert_eq!(metar.ceiling(), Some(900));
+    assert_eq!(metar.flight_category(), Some(FlightCategory::Instrument));
+
+    let metar = Metar::parse("LFVP 232230Z AUTO 24009KT 0450 FG VV002 11/11 Q1015").unwrap();
+    assert_eq!(metar.ceiling(), Some(200));
+    assert_eq!(metar.flight_category(), Some(FlightCategory::LowInstrument));
+
+    let metar = Metar::parse("EDDM 222020Z AUTO VRB01KT CAVOK 20/13 Q1017").unwrap();
+    assert_eq!(metar.ceiling(), None);
+    assert_eq!(metar.flight_category(), Some(FlightCategory::Visual));
+}
+
+#[test]
+fn test_pressure_altitude() {
+    let pressure_altitude = |pressure: &str, elevation| {
+        Metar::parse(&format!(
+            "EGLL 010000Z 24010KT 9999 FEW030 16/14 {pressure}"
+        ))
+        .unwrap()
+        .pressure_altitude(elevation)
+    };
+    assert!(pressure_altitude("Q1013", 0.).unwrap().abs() < 10.);
+    assert!((pressure_altitude("A2992", 0.).unwrap()).abs() < 0.01);
+    assert!((pressure_altitude("A2982", 500.).unwrap() - 600.).abs() < 0.1);
+    assert_eq!(pressure_altitude("Q////", 0.), None);
+}
+
+#[test]
+fn test_estimated_freezing_level() {
+    let freezing_level = |temperature: &str| {
+        Metar::parse(&format!(
+            "EGLL 010000Z 24010KT 9999 FEW030 {temperature}/M10 Q1013"
+        ))
+        .unwrap()
+        .estimated_freezing_level_ft()
+    };
+    assert!((freezing_level("10").unwrap() - 5000.).abs() < 1.);
+    assert!((freezing_level("03").unwrap() - 1500.).abs() < 1.);
+    assert_eq!(freezing_level("M05"), Some(0.));
+    assert_eq!(freezing_level("//"), None);
+}
+
+#[test]
+fn test_ceiling_category() {
+    let category = |clouds: &str| {
+        Metar::parse(&format!("EGLL 010000Z 24010KT 9999 {clouds} 16/14 Q1006"))
+            .unwrap()
+            .ceiling_category()
+    };
+    assert_eq!(category("FEW010 SCT020"), CeilingCategory::Clear);
+    assert_eq!(category("NCD"), CeilingCategory::Clear);
+    assert_eq!(category("BKN060"), CeilingCategory::High);
+    assert_eq!(category("BKN050"), CeilingCategory::Medium);
+    assert_eq!(category("SCT005 OVC010"), CeilingCategory::Medium);
+    assert_eq!(category("BKN009"), CeilingCategory::Low);
+    assert_eq!(category("BKN005"), CeilingCategory::Low);
+    assert_eq!(category("OVC004"), CeilingCategory::VeryLow);
+    assert_eq!(category("VV002"), CeilingCategory::VeryLow);
+}
+
+#[test]
+fn test_triggers_speci() {
+    let previous = Metar::parse("EGLL 010950Z 24010KT 9999 BKN015 16/14 Q1006").unwrap();
+
+    // Ceiling falling through the 1000ft IFR threshold
+    let metar = Metar::parse("EGLL 011010Z 24010KT 9999 BKN008 16/14 Q1006").unwrap();
+    assert!(metar.triggers_speci(&previous));
+    assert!(previous.triggers_speci(&metar));
+
+    // Ceiling rising without crossing a threshold
+    let metar = Metar::parse("EGLL 011010Z 24010KT 9999 BKN020 16/14 Q1006").unwrap();
+    assert!(!metar.triggers_speci(&previous));
+
+    // Visibility falling through 5000m
+    let metar = Metar::parse("EGLL 011010Z 24010KT 4000 BKN015 16/14 Q1006").unwrap();
+    assert!(metar.triggers_speci(&previous));
+
+    // Onset of a thunderstorm
+    let metar = Metar::parse("EGLL 011010Z 24010KT 9999 TS BKN015 16/14 Q1006").unwrap();
+    assert!(metar.triggers_speci(&previous));
+
+    // Insignificant weather
+    let metar = Metar::parse("EGLL 011010Z 24010KT 9999 -RA BKN015 16/14 Q1006").unwrap();
+    assert!(!metar.triggers_speci(&previous));
+}
+
+#[test]
+fn test_wind_predicates() {
+    let metar =
+        |wind: &str| Metar::parse(&format!("EGLL 010000Z {wind} 9999 FEW030 16/14 Q1006")).unwrap();
+
+    let vrb = metar("VRB03KT");
+    assert!(vrb.wind_is_variable());
+    assert!(!vrb.wind_is_gusting());
+
+    let gusting = metar("25015G30KT");
+    assert!(!gusting.wind_is_variable());
+    assert!(gusting.wind_is_gusting());
+
+    assert!(metar("25015KT 210V280").wind_is_variable());
+    assert!(metar("25015G//KT").wind_is_gusting());
+    assert!(!metar("00000KT").wind_is_variable());
+    assert!(!metar("CALM").wind_is_gusting());
+}
+
+#[test]
+fn test_unknown_wind_direction() {
+    let wind = |wind: &str| {
+        Metar::parse(&format!("EGLL 010000Z AUTO {wind} 9999 FEW030 16/14 Q1006"))
+            .unwrap()
+            .wind
+    };
+    let unknown_direction = |speed| Wind::Present {
+        dir: WindDirection::Heading(Data::Unknown),
+        speed: WindSpeed::Knots {
+            speed,
+            gusting: None,
+        },
+        varying: None,
+    };
+    assert_eq!(wind("/////KT"), unknown_direction(Data::Unknown));
+    assert_eq!(wind("///05KT"), unknown_direction(Data::Known(5)));
+    assert_eq!(wind("///ND05KT"), unknown_direction(Data::Known(5)));
+
+    // The "no direction" marker is normalised away
+    let metar = Metar::parse("EGLL 010000Z AUTO ///ND05KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z AUTO ///05KT 9999 FEW030 16/14 Q1006"
+    );
+    assert_eq!(Metar::parse(&metar.to_string()).unwrap(), metar);
+}
+
+#[test]
+fn test_wind_vector_knots() {
+    let vector = |wind: &str| {
+        Metar::parse(&format!("EGLL 010000Z {wind} 9999 FEW030 16/14 Q1006"))
+            .unwrap()
+            .wind_vector_knots()
+    };
+    let approx = |wind: &str, (u, v): (f32, f32)| {
+        let (actual_u, actual_v) = vector(wind).unwrap();
+        assert!((actual_u - u).abs() < 0.01, "{wind}: u was {actual_u}");
+        assert!((actual_v - v).abs() < 0.01, "{wind}: v was {actual_v}");
+    };
+    approx("27010KT", (10., 0.));
+    approx("36010KT", (0., -10.));
+    approx("18005MPS", (0., 9.72));
+    approx("00000KT", (0., 0.));
+    assert_eq!(vector("VRB03KT"), None);
+    assert_eq!(vector("///10KT"), None);
+}
+
+#[test]
+fn test_to_ascii_table() {
+    let metar =
+        Metar::parse("EGHI 282120Z 19015G25KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006").unwrap();
+    let table = metar.to_ascii_table();
+    let lines = table.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("+-"));
+    assert!(
+        lines.contains(&"| Wind            | 190 deg at 15KT gusting 25KT, varying 140-220 deg |")
+    );
+    for line in [
+        "| Station         | EGHI ",
+        "| Visibility      | 6000m",
+        "| Ceiling         | 900ft",
+        "| Temperature     | 16C",
+        "| Humidity        | 88%",
+        "| Pressure        | 1006hPa",
+        "| Flight category | IFR",
+    ] {
+        assert!(table.contains(line), "missing {line:?} in\n{table}");
+    }
+}
+
+#[test]
+fn test_recent_weather_before_clouds() {
+    let metar = Metar::parse("EDDM 231520Z 25012KT 9999 -RA RERA SCT020 14/09 Q1012").unwrap();
+    assert_eq!(metar.weather.unwrap().len(), 1);
+    assert_eq!(
+        metar.recent_weather,
+        vec![Data::Known(vec![WeatherCondition::Rain])]
+    );
+}
+
+#[test]
+fn test_amended() {
+    let metar = Metar::parse("METAR AMD EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006").unwrap();
+    assert_eq!(metar.kind, Kind::Amended);
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z AMD 24010KT 9999 FEW030 16/14 Q1006"
+    );
+    assert_eq!(Metar::parse(&metar.to_string()).unwrap(), metar);
+}
+
+#[test]
+fn test_nil() {
+    let metar = Metar::parse("EGLL 282120Z NIL").unwrap();
+    assert!(metar.nil);
+    assert_eq!(metar.station, "EGLL");
+    assert_eq!(metar.visibility, Data::Unknown);
+    assert_eq!(metar.to_string(), "EGLL 282120Z NIL");
+
+    let metar = Metar::parse("METAR EGLL 282120Z NIL=").unwrap();
+    assert!(metar.nil);
+}
+
+#[test]
+fn test_runways_mentioned() {
+    let metar =
+        Metar::parse("EGLL 010000Z 24010KT 2000 R24/1200N R27/P1500N BR FEW030 16/14 Q1006 WS R24")
+            .unwrap();
+    assert_eq!(metar.runways_mentioned(), vec!["24", "27"]);
+
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 SCT006 16/14 Q1006").unwrap();
+    assert!(metar.runways_mentioned().is_empty());
+}
+
+#[test]
+fn test_windshear_warnings() {
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 WS R24 WS R06").unwrap();
+    assert_eq!(
+        metar.windshear_warnings,
+        Some(WindshearWarnings::Runways(vec![
+            "24".to_string(),
+            "06".to_string()
+        ]))
+    );
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 WS R24 WS R06"
+    );
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 WS ALL RWY").unwrap();
+    assert_eq!(metar.windshear_warnings, Some(WindshearWarnings::All));
+    assert_eq!(
+        metar.to_string(),
+        "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 WS ALL RWY"
+    );
+}
+
+#[test]
+fn test_core_eq() {
+    let metar = Metar::parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006 RMK AO2").unwrap();
+    let other =
+        Metar::parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/14 Q1006 TEMPO 3000 RMK AO2 SLP142")
+            .unwrap();
+    assert_ne!(metar, other);
+    assert!(metar.core_eq(&other));
+
+    let other = Metar::parse("EGHI 282120Z 19015KT 6000 RA SCT006 16/13 Q1006 RMK AO2").unwrap();
+    assert!(!metar.core_eq(&other));
+}
+
+#[test]
+fn test_clouds_in_vicinity() {
+    let metar_str = "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 CB/N CB/SE";
+    let metar = Metar::parse(metar_str).unwrap();
+    assert_eq!(
+        metar.clouds_in_vicinity,
+        vec![
+            (
+                vec![CompassDirection::North],
+                Data::Known(CloudType::Cumulonimbus)
+            ),
+            (
+                vec![CompassDirection::SouthEast],
+                Data::Known(CloudType::Cumulonimbus)
+            ),
+        ]
+    );
+
+    let metar = Metar::parse("EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 TCU/SW/W").unwrap();
+    assert_eq!(
+        metar.clouds_in_vicinity,
+        vec![(
+            vec![CompassDirection::SouthWest, CompassDirection::West],
+            Data::Known(CloudType::ToweringCumulus)
+        )]
+    );
+
+    for metar_str in [
+        "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 ///SW",
+        "EGLL 010000Z 24010KT 9999 FEW030 16/14 Q1006 ////SW",
+    ] {
+        let metar = Metar::parse(metar_str).unwrap();
+        assert_eq!(
+            metar.clouds_in_vicinity,
+            vec![(vec![CompassDirection::SouthWest], Data::Unknown)]
+        );
+    }
+}
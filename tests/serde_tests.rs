@@ -81,6 +81,50 @@ mod serde_tests {
             assert_eq!(metar, deserialized, "Roundtrip failed for: {}", metar_str);
         }
     }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct RawMetar {
+        #[serde(with = "metar::serde_raw")]
+        metar: Metar,
+    }
+
+    #[test]
+    fn test_deserialize_from_raw_string() {
+        let metar_str = "EGLL 282120Z 19015KT 140V220 6000 RA SCT006 BKN009 16/14 Q1006";
+        let json = format!("{{\"metar\":\"{metar_str}\"}}");
+
+        let raw: RawMetar = serde_json::from_str(&json).unwrap();
+        assert_eq!(raw.metar, Metar::parse(metar_str).unwrap());
+
+        // Serialises back to the original string
+        assert_eq!(serde_json::to_string(&raw).unwrap(), json);
+    }
+
+    #[test]
+    fn test_deserialize_from_invalid_raw_string() {
+        let result = serde_json::from_str::<RawMetar>(r#"{"metar":"EGLL 282120Z NOT A METAR"}"#);
+        assert!(result.is_err());
+
+        let result = serde_json::from_str::<RawMetar>(r#"{"metar":42}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_without_newer_fields() {
+        // JSON serialised by earlier versions lacks fields added since
+        let metar = Metar::parse("EGHI 282120Z 19015KT 6000 SCT006 16/14 Q1006").unwrap();
+        let mut json = serde_json::to_value(&metar).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        for field in ["nil", "no_directional_variation", "maintenance_indicator"] {
+            fields.remove(field);
+        }
+
+        let deserialized: Metar = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, metar);
+        assert!(!deserialized.nil);
+        assert!(!deserialized.no_directional_variation);
+        assert!(!deserialized.maintenance_indicator);
+    }
 }
 
 #[cfg(not(feature = "serde"))]